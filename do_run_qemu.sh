@@ -43,7 +43,8 @@ print_title "launching QEMU" &&
 qemu-system-x86_64 -enable-kvm \
 -drive if=pflash,format=raw,readonly=on,file=qemu/OVMF_CODE.fd \
 -drive if=pflash,format=raw,readonly=on,file=qemu/OVMF_VARS.fd  \
--drive format=raw,file=fat:rw:qemu/esp &&
+-drive format=raw,file=fat:rw:qemu/esp \
+-serial stdio &&
 
 print_success &&
 rm -f qemu/esp/efi/boot/bootx64.efi &&
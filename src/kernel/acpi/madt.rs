@@ -0,0 +1,106 @@
+use crate::kernel::acpi::{find_table, SdtHeader};
+use alloc::vec::Vec;
+use core::mem;
+
+const MADT_SIGNATURE: &[u8; 4] = b"APIC";
+const PCAT_COMPATIBLE_FLAG: u32 = 1;
+
+const IO_APIC_ENTRY_TYPE: u8 = 1;
+const INTERRUPT_SOURCE_OVERRIDE_ENTRY_TYPE: u8 = 2;
+const LOCAL_APIC_ADDRESS_OVERRIDE_ENTRY_TYPE: u8 = 5;
+
+const ACTIVE_LOW_POLARITY: u16 = 0b11;
+const LEVEL_TRIGGER_MODE: u16 = 0b11 << 2;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct IoApicInfo {
+    pub(crate) address: u64,
+    /// First global system interrupt handled by this IO APIC
+    pub(crate) gsi_base: u32,
+}
+
+/// How a legacy ISA IRQ is wired to a global system interrupt
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct IsaIrqRoute {
+    pub(crate) gsi: u32,
+    pub(crate) is_active_low: bool,
+    pub(crate) is_level_triggered: bool,
+}
+
+/// What the kernel needs from the Multiple APIC Description Table
+#[derive(Debug)]
+pub(crate) struct Madt {
+    pub(crate) local_apic_address: u64,
+    pub(crate) io_apics: Vec<IoApicInfo>,
+    /// Indexed by ISA IRQ
+    pub(crate) isa_irq_routes: [IsaIrqRoute; 16],
+    pub(crate) has_legacy_pics: bool,
+}
+
+#[repr(C, packed)]
+struct MadtHeader {
+    sdt_header: SdtHeader,
+    local_apic_address: u32,
+    flags: u32,
+}
+
+impl Madt {
+    /// # Safety
+    /// `rsdp_address` must be the address of the RSDP, and the ACPI tables must be identity mapped
+    pub(crate) unsafe fn find(rsdp_address: u64) -> Option<Self> {
+        let madt_address = find_table(rsdp_address, MADT_SIGNATURE)?;
+        let header = (madt_address as *const MadtHeader).read_unaligned();
+        let mut madt = Self {
+            local_apic_address: header.local_apic_address as u64,
+            io_apics: Vec::new(),
+            // ISA IRQs are identity mapped to global system interrupts, unless overridden
+            isa_irq_routes: core::array::from_fn(|isa_irq| IsaIrqRoute {
+                gsi: isa_irq as u32,
+                is_active_low: false,
+                is_level_triggered: false,
+            }),
+            has_legacy_pics: header.flags & PCAT_COMPATIBLE_FLAG != 0,
+        };
+        let end_address = madt_address + header.sdt_header.length as u64;
+        let mut entry_address = madt_address + mem::size_of::<MadtHeader>() as u64;
+        while entry_address + 2 <= end_address {
+            let entry = entry_address as *const u8;
+            let entry_type = entry.read();
+            let entry_length = entry.add(1).read();
+            if entry_length < 2 {
+                break;
+            }
+            madt.parse_entry(entry_type, entry);
+            entry_address += entry_length as u64;
+        }
+        Some(madt)
+    }
+
+    /// # Safety
+    /// `entry` must point to a whole MADT entry of type `entry_type`
+    unsafe fn parse_entry(&mut self, entry_type: u8, entry: *const u8) {
+        let read_u16 = |offset: usize| entry.add(offset).cast::<u16>().read_unaligned();
+        let read_u32 = |offset: usize| entry.add(offset).cast::<u32>().read_unaligned();
+        match entry_type {
+            IO_APIC_ENTRY_TYPE => self.io_apics.push(IoApicInfo {
+                address: read_u32(4) as u64,
+                gsi_base: read_u32(8),
+            }),
+            INTERRUPT_SOURCE_OVERRIDE_ENTRY_TYPE => {
+                let isa_irq = entry.add(3).read() as usize;
+                let flags = read_u16(8);
+                if let Some(route) = self.isa_irq_routes.get_mut(isa_irq) {
+                    *route = IsaIrqRoute {
+                        gsi: read_u32(4),
+                        is_active_low: flags & ACTIVE_LOW_POLARITY == ACTIVE_LOW_POLARITY,
+                        is_level_triggered: flags & LEVEL_TRIGGER_MODE == LEVEL_TRIGGER_MODE,
+                    };
+                }
+            }
+            LOCAL_APIC_ADDRESS_OVERRIDE_ENTRY_TYPE => {
+                self.local_apic_address = entry.add(4).cast::<u64>().read_unaligned();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use core::mem;
+use uefi::table::cfg::{ACPI2_GUID, ACPI_GUID};
+use uefi::table::{Runtime, SystemTable};
+
+pub(crate) mod madt;
+
+/// Root System Description Pointer, as found through the UEFI configuration table
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct Rsdp {
+    signature: [u8; 8],
+    checksum: u8,
+    oem_id: [u8; 6],
+    revision: u8,
+    rsdt_address: u32,
+    // Only valid for ACPI 2.0 and above
+    length: u32,
+    xsdt_address: u64,
+    extended_checksum: u8,
+    reserved: [u8; 3],
+}
+
+/// Header shared by every System Description Table
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub(crate) struct SdtHeader {
+    pub(crate) signature: [u8; 4],
+    pub(crate) length: u32,
+    revision: u8,
+    checksum: u8,
+    oem_id: [u8; 6],
+    oem_table_id: [u8; 8],
+    oem_revision: u32,
+    creator_id: u32,
+    creator_revision: u32,
+}
+
+/// Returns the physical address of the RSDP, preferring the ACPI 2.0 one
+pub(crate) fn find_rsdp_address(system_table: &SystemTable<Runtime>) -> Option<u64> {
+    let config_table = system_table.config_table();
+    config_table
+        .iter()
+        .find(|entry| entry.guid == ACPI2_GUID)
+        .or_else(|| config_table.iter().find(|entry| entry.guid == ACPI_GUID))
+        .map(|entry| entry.address as u64)
+}
+
+/// # Safety
+/// `address` must point to `length` readable bytes
+unsafe fn has_valid_checksum(address: u64, length: usize) -> bool {
+    (0..length)
+        .map(|offset| (address as *const u8).add(offset).read())
+        .fold(0u8, u8::wrapping_add)
+        == 0
+}
+
+/// Returns the physical address of the first table whose signature is `signature`,
+/// walking the XSDT, or the RSDT before ACPI 2.0
+///
+/// # Safety
+/// `rsdp_address` must be the address of the RSDP, and the ACPI tables must be identity mapped
+pub(crate) unsafe fn find_table(rsdp_address: u64, signature: &[u8; 4]) -> Option<u64> {
+    let rsdp = (rsdp_address as *const Rsdp).read_unaligned();
+    if &rsdp.signature != b"RSD PTR " || !has_valid_checksum(rsdp_address, 20) {
+        return None;
+    }
+    let (root_address, entry_size) = if rsdp.revision >= 2 && rsdp.xsdt_address != 0 {
+        (rsdp.xsdt_address, mem::size_of::<u64>())
+    } else {
+        (rsdp.rsdt_address as u64, mem::size_of::<u32>())
+    };
+    let root_header = (root_address as *const SdtHeader).read_unaligned();
+    let entry_count = (root_header.length as usize - mem::size_of::<SdtHeader>()) / entry_size;
+    let entries_address = root_address + mem::size_of::<SdtHeader>() as u64;
+    (0..entry_count)
+        .map(|index| {
+            let entry_address = entries_address + (index * entry_size) as u64;
+            if entry_size == mem::size_of::<u64>() {
+                (entry_address as *const u64).read_unaligned()
+            } else {
+                (entry_address as *const u32).read_unaligned() as u64
+            }
+        })
+        .find(|&table_address| {
+            let header = (table_address as *const SdtHeader).read_unaligned();
+            &header.signature == signature
+                && has_valid_checksum(table_address, header.length as usize)
+        })
+}
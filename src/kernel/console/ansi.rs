@@ -0,0 +1,299 @@
+use crate::kernel::console::char_buffer::CharColors;
+use crate::kernel::native_graphics::Pixel;
+
+const ESCAPE: char = '\u{1b}';
+const MAX_PARAMETER_COUNT: usize = 16;
+
+/// A control sequence, like `ESC [ 1 ; 31 m`
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ControlSequence {
+    parameters: [u16; MAX_PARAMETER_COUNT],
+    parameter_count: usize,
+    /// Sequences starting with `?` are DEC private modes
+    pub(super) is_private: bool,
+    pub(super) final_char: char,
+}
+
+impl ControlSequence {
+    const EMPTY: Self = Self {
+        parameters: [0; MAX_PARAMETER_COUNT],
+        parameter_count: 0,
+        is_private: false,
+        final_char: '\0',
+    };
+
+    /// Missing and zero parameters both mean `default`
+    pub(super) fn get(&self, index: usize, default: u16) -> u16 {
+        match self.parameters().get(index) {
+            Some(0) | None => default,
+            Some(&parameter) => parameter,
+        }
+    }
+    pub(super) fn parameters(&self) -> &[u16] {
+        &self.parameters[..self.parameter_count.min(MAX_PARAMETER_COUNT)]
+    }
+
+    fn push_digit(&mut self, digit: u32) {
+        if self.parameter_count == 0 {
+            self.parameter_count = 1;
+        }
+        if let Some(parameter) = self.parameters.get_mut(self.parameter_count - 1) {
+            *parameter = parameter.saturating_mul(10).saturating_add(digit as u16);
+        }
+    }
+    fn next_parameter(&mut self) {
+        if self.parameter_count == 0 {
+            self.parameter_count = 1;
+        }
+        // The parameters after the last one kept are ignored
+        self.parameter_count = (self.parameter_count + 1).min(MAX_PARAMETER_COUNT + 1);
+    }
+}
+
+/// What the console must do for the chars it was given
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Action {
+    Print(char),
+    /// A C0 control char, like `\n`
+    Execute(char),
+    /// `ESC` followed by a single char
+    Escape(char),
+    Control(ControlSequence),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ParserState {
+    Ground,
+    Escape,
+    ControlSequence(ControlSequence),
+    /// Intermediate bytes were found, which no supported sequence uses
+    IgnoredControlSequence,
+}
+
+/// Splits the printed chars into chars to draw and VT100 escape sequences
+///
+/// The state is kept between prints, so a sequence may be split across several of them
+#[derive(Clone, Copy, Debug)]
+pub(super) struct EscapeParser {
+    state: ParserState,
+}
+
+impl EscapeParser {
+    pub(super) const fn new() -> Self {
+        Self {
+            state: ParserState::Ground,
+        }
+    }
+
+    pub(super) fn advance(&mut self, c: char) -> Option<Action> {
+        // Like on a VT100, a new escape sequence aborts the current one
+        if c == ESCAPE {
+            self.state = ParserState::Escape;
+            return None;
+        }
+        match &mut self.state {
+            ParserState::Ground if c.is_control() => Some(Action::Execute(c)),
+            ParserState::Ground => Some(Action::Print(c)),
+            ParserState::Escape => {
+                if c == '[' {
+                    self.state = ParserState::ControlSequence(ControlSequence::EMPTY);
+                    return None;
+                }
+                self.state = ParserState::Ground;
+                Some(Action::Escape(c))
+            }
+            ParserState::ControlSequence(sequence) => match c {
+                '0'..='9' => {
+                    sequence.push_digit(c as u32 - '0' as u32);
+                    None
+                }
+                // The colon separates the sub-parameters of the ITU T.416 colors
+                ';' | ':' => {
+                    sequence.next_parameter();
+                    None
+                }
+                '?' => {
+                    sequence.is_private = true;
+                    None
+                }
+                '\u{20}'..='\u{2f}' => {
+                    self.state = ParserState::IgnoredControlSequence;
+                    None
+                }
+                '\u{40}'..='\u{7e}' => {
+                    let mut sequence = *sequence;
+                    sequence.final_char = c;
+                    self.state = ParserState::Ground;
+                    Some(Action::Control(sequence))
+                }
+                _ => self.abort(c),
+            },
+            ParserState::IgnoredControlSequence => match c {
+                '\u{40}'..='\u{7e}' => {
+                    self.state = ParserState::Ground;
+                    None
+                }
+                '\u{20}'..='\u{3f}' => None,
+                _ => self.abort(c),
+            },
+        }
+    }
+
+    /// Control chars are still executed in the middle of a sequence, the other chars cancel it
+    fn abort(&mut self, c: char) -> Option<Action> {
+        if c.is_control() {
+            return Some(Action::Execute(c));
+        }
+        self.state = ParserState::Ground;
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum AnsiColor {
+    Default,
+    /// One of the 256 colors of xterm, the first 16 being the standard and bright ones
+    Indexed(u8),
+    Rgb(Pixel),
+}
+
+impl AnsiColor {
+    const STANDARD_COLORS: [Pixel; 16] = [
+        Pixel::rgb(0, 0, 0),
+        Pixel::rgb(205, 0, 0),
+        Pixel::rgb(0, 205, 0),
+        Pixel::rgb(205, 205, 0),
+        Pixel::rgb(0, 0, 238),
+        Pixel::rgb(205, 0, 205),
+        Pixel::rgb(0, 205, 205),
+        Pixel::rgb(229, 229, 229),
+        Pixel::rgb(127, 127, 127),
+        Pixel::rgb(255, 0, 0),
+        Pixel::rgb(0, 255, 0),
+        Pixel::rgb(255, 255, 0),
+        Pixel::rgb(92, 92, 255),
+        Pixel::rgb(255, 0, 255),
+        Pixel::rgb(0, 255, 255),
+        Pixel::rgb(255, 255, 255),
+    ];
+    const FIRST_CUBE_INDEX: u8 = 16;
+    const FIRST_GRAY_INDEX: u8 = 232;
+
+    const fn get_indexed_pixel(index: u8) -> Pixel {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        if index < Self::FIRST_CUBE_INDEX {
+            Self::STANDARD_COLORS[index as usize]
+        } else if index < Self::FIRST_GRAY_INDEX {
+            let cube_index = (index - Self::FIRST_CUBE_INDEX) as usize;
+            Pixel::rgb(
+                CUBE_LEVELS[cube_index / 36],
+                CUBE_LEVELS[cube_index / 6 % 6],
+                CUBE_LEVELS[cube_index % 6],
+            )
+        } else {
+            let level = 8 + 10 * (index - Self::FIRST_GRAY_INDEX);
+            Pixel::rgb(level, level, level)
+        }
+    }
+
+    /// Bold standard colors are drawn with their bright variant, like on the Linux console
+    const fn get_pixel(&self, default: Pixel, is_bold: bool) -> Pixel {
+        match *self {
+            Self::Default => default,
+            Self::Indexed(index) if is_bold && index < 8 => Self::get_indexed_pixel(index + 8),
+            Self::Indexed(index) => Self::get_indexed_pixel(index),
+            Self::Rgb(pixel) => pixel,
+        }
+    }
+
+    /// Parses the parameters following 38 or 48, which are `5;<index>` or `2;<red>;<green>;<blue>`
+    ///
+    /// Returns the color, if valid, and the count of parameters read
+    fn parse_extended(parameters: &[u16]) -> (Option<Self>, usize) {
+        let to_u8 = |parameter: &u16| u8::try_from(*parameter).ok();
+        match parameters {
+            [5, index, ..] => (to_u8(index).map(Self::Indexed), 2),
+            [2, red, green, blue, ..] => {
+                let pixel = to_u8(red)
+                    .zip(to_u8(green))
+                    .zip(to_u8(blue))
+                    .map(|((red, green), blue)| Pixel::rgb(red, green, blue));
+                (pixel.map(Self::Rgb), 4)
+            }
+            _ => (None, parameters.len()),
+        }
+    }
+}
+
+/// The colors and attributes set through SGR sequences, like `ESC [ 1 ; 31 m`
+#[derive(Clone, Copy, Debug)]
+pub(super) struct GraphicRendition {
+    foreground: AnsiColor,
+    background: AnsiColor,
+    is_bold: bool,
+    pub(super) is_underlined: bool,
+    is_inverse: bool,
+}
+
+impl GraphicRendition {
+    pub(super) const DEFAULT: Self = Self {
+        foreground: AnsiColor::Default,
+        background: AnsiColor::Default,
+        is_bold: false,
+        is_underlined: false,
+        is_inverse: false,
+    };
+
+    /// Unsupported parameters are ignored
+    pub(super) fn select(&mut self, parameters: &[u16]) {
+        if parameters.is_empty() {
+            *self = Self::DEFAULT;
+            return;
+        }
+        let mut index = 0;
+        while index < parameters.len() {
+            let parameter = parameters[index];
+            index += 1;
+            match parameter {
+                0 => *self = Self::DEFAULT,
+                1 => self.is_bold = true,
+                4 => self.is_underlined = true,
+                7 => self.is_inverse = true,
+                22 => self.is_bold = false,
+                24 => self.is_underlined = false,
+                27 => self.is_inverse = false,
+                30..=37 => self.foreground = AnsiColor::Indexed((parameter - 30) as u8),
+                39 => self.foreground = AnsiColor::Default,
+                40..=47 => self.background = AnsiColor::Indexed((parameter - 40) as u8),
+                49 => self.background = AnsiColor::Default,
+                90..=97 => self.foreground = AnsiColor::Indexed((parameter - 90 + 8) as u8),
+                100..=107 => self.background = AnsiColor::Indexed((parameter - 100 + 8) as u8),
+                38 | 48 => {
+                    let (color, parameter_count) = AnsiColor::parse_extended(&parameters[index..]);
+                    index += parameter_count;
+                    match (parameter, color) {
+                        (38, Some(color)) => self.foreground = color,
+                        (48, Some(color)) => self.background = color,
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(super) fn get_char_colors(&self, default: CharColors) -> CharColors {
+        let colors = CharColors {
+            foreground: self.foreground.get_pixel(default.foreground, self.is_bold),
+            background: self.background.get_pixel(default.background, false),
+        };
+        if self.is_inverse {
+            CharColors {
+                foreground: colors.background,
+                background: colors.foreground,
+            }
+        } else {
+            colors
+        }
+    }
+}
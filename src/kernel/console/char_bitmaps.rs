@@ -1,4 +1,3 @@
-use crate::kernel::console::char_buffer::PrintableChar;
 use crate::kernel::native_graphics::Resolution;
 
 const CHAR_WIDTH: usize = 8;
@@ -8,9 +7,9 @@ pub(super) const CHAR_RESOLUTION: Resolution = Resolution {
     vertical: CHAR_HEIGHT,
 };
 
-const FIRST_PRINTABLE_CHAR: usize = 0x20;
-const LAST_PRINTABLE_CHAR: usize = 0x7E;
-const PRINTABLE_CHAR_COUNT: usize = LAST_PRINTABLE_CHAR + 1 - FIRST_PRINTABLE_CHAR;
+/// Drawn for the chars missing from a font
+pub(super) const REPLACEMENT_CHAR: char = '\u{fffd}';
+const GLYPH_COUNT: usize = 481;
 
 #[derive(Clone, Copy, Debug)]
 pub enum CharBit {
@@ -28,1850 +27,10599 @@ impl From<bool> for CharBit {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub(super) struct CharBitLine(u8);
-
-impl CharBitLine {
-    pub(super) fn get_bits(&self) -> [CharBit; CHAR_WIDTH] {
-        [
-            CharBit::from(self.0 & 128 != 0),
-            CharBit::from(self.0 & 64 != 0),
-            CharBit::from(self.0 & 32 != 0),
-            CharBit::from(self.0 & 16 != 0),
-            CharBit::from(self.0 & 8 != 0),
-            CharBit::from(self.0 & 4 != 0),
-            CharBit::from(self.0 & 2 != 0),
-            CharBit::from(self.0 & 1 != 0),
-        ]
-    }
-}
-
-#[derive(Clone, Copy, Debug)]
-pub(super) struct CharBitMap([CharBitLine; CHAR_HEIGHT]);
-
-impl CharBitMap {
-    pub(super) fn get_lines(&self) -> [CharBitLine; CHAR_HEIGHT] {
-        self.0
-    }
+/// The bitmap of `c` in the built-in font, if any
+pub(super) fn find_bitmap(c: char) -> Option<&'static [u8; CHAR_HEIGHT]> {
+    GLYPHS
+        .binary_search_by_key(&c, |&(glyph_char, _)| glyph_char)
+        .ok()
+        .map(|glyph_index| &GLYPHS[glyph_index].1)
 }
 
-impl From<PrintableChar> for CharBitMap {
-    fn from(printable_char: PrintableChar) -> Self {
-        let char_index = printable_char.get_index_from_first_printable_char();
-        let char_bitmap = CHAR_BITMAPS[char_index];
-        CharBitMap(char_bitmap.map(|line| CharBitLine(line)))
-    }
-}
-
-/// One byte is one line of one char's bitmap
-///
-/// The first 16 bytes are the 16 lines of the first char, and so on...
+/// One byte is one line of one char's bitmap, and the glyphs are sorted by char, so that any code point may be missing
 ///
-/// Bitmap from Terminus 8x16 regular PSF font
-const CHAR_BITMAPS: [[u8; CHAR_HEIGHT]; PRINTABLE_CHAR_COUNT] = [
-    // 32 0x20 ' '
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 33 0x21 '!'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 34 0x22 '"'
-    [
-        0b00000000, //
-        0b00100100, //
-        0b00100100, //
-        0b00100100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 35 0x23 '#'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00100100, //
-        0b00100100, //
-        0b00100100, //
-        0b01111110, //
-        0b00100100, //
-        0b00100100, //
-        0b01111110, //
-        0b00100100, //
-        0b00100100, //
-        0b00100100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 36 0x24 '$'
-    [
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b01111100, //
-        0b10010010, //
-        0b10010000, //
-        0b10010000, //
-        0b01111100, //
-        0b00010010, //
-        0b00010010, //
-        0b10010010, //
-        0b01111100, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 37 0x25 '%'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01100100, //
-        0b10010100, //
-        0b01101000, //
-        0b00001000, //
-        0b00010000, //
-        0b00010000, //
-        0b00100000, //
-        0b00101100, //
-        0b01010010, //
-        0b01001100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 38 0x26 '&'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00011000, //
-        0b00100100, //
-        0b00100100, //
-        0b00011000, //
-        0b00110000, //
-        0b01001010, //
-        0b01000100, //
-        0b01000100, //
-        0b01000100, //
-        0b00111010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 39 0x27 '''
-    [
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 40 0x28 '('
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00001000, //
-        0b00010000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00010000, //
-        0b00001000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 41 0x29 ')'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00100000, //
-        0b00010000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00010000, //
-        0b00100000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 42 0x2a '*'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00100100, //
-        0b00011000, //
-        0b01111110, //
-        0b00011000, //
-        0b00100100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 43 0x2b '+'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b01111100, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 44 0x2c ','
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00100000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 45 0x2d '-'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 46 0x2e '.'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 47 0x2f '/'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000100, //
-        0b00000100, //
-        0b00001000, //
-        0b00001000, //
-        0b00010000, //
-        0b00010000, //
-        0b00100000, //
-        0b00100000, //
-        0b01000000, //
-        0b01000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 48 0x30 '0'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000110, //
-        0b01001010, //
-        0b01010010, //
-        0b01100010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 49 0x31 '1'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00001000, //
-        0b00011000, //
-        0b00101000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 50 0x32 '2'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b00000010, //
-        0b00000100, //
-        0b00001000, //
-        0b00010000, //
-        0b00100000, //
-        0b01000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 51 0x33 '3'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b00000010, //
-        0b00011100, //
-        0b00000010, //
-        0b00000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 52 0x34 '4'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000010, //
-        0b00000110, //
-        0b00001010, //
-        0b00010010, //
-        0b00100010, //
-        0b01000010, //
-        0b01111110, //
-        0b00000010, //
-        0b00000010, //
-        0b00000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 53 0x35 '5'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111100, //
-        0b00000010, //
-        0b00000010, //
-        0b00000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 54 0x36 '6'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00011100, //
-        0b00100000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 55 0x37 '7'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b00000010, //
-        0b00000010, //
-        0b00000100, //
-        0b00000100, //
-        0b00001000, //
-        0b00001000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 56 0x38 '8'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 57 0x39 '9'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111110, //
-        0b00000010, //
-        0b00000010, //
-        0b00000100, //
-        0b00111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 58 0x3a ':'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 59 0x3b ';'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00100000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 60 0x3c '<'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000100, //
-        0b00001000, //
-        0b00010000, //
-        0b00100000, //
-        0b01000000, //
-        0b00100000, //
-        0b00010000, //
-        0b00001000, //
-        0b00000100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 61 0x3d '='
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 62 0x3e '>'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01000000, //
-        0b00100000, //
-        0b00010000, //
-        0b00001000, //
-        0b00000100, //
-        0b00001000, //
-        0b00010000, //
-        0b00100000, //
-        0b01000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 63 0x3f '?'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00000100, //
-        0b00001000, //
-        0b00001000, //
-        0b00000000, //
-        0b00001000, //
-        0b00001000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 64 0x40 '@'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111100, //
-        0b10000010, //
-        0b10011110, //
-        0b10100010, //
-        0b10100010, //
-        0b10100010, //
-        0b10100110, //
-        0b10011010, //
-        0b10000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 65 0x41 'A'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 66 0x42 'B'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 67 0x43 'C'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 68 0x44 'D'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111000, //
-        0b01000100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000100, //
-        0b01111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 69 0x45 'E'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 70 0x46 'F'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 71 0x47 'G'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000000, //
-        0b01000000, //
-        0b01001110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 72 0x48 'H'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 73 0x49 'I'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 74 0x4a 'J'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00001110, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b01000100, //
-        0b01000100, //
-        0b00111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 75 0x4b 'K'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000100, //
-        0b01001000, //
-        0b01010000, //
-        0b01100000, //
-        0b01100000, //
-        0b01010000, //
-        0b01001000, //
-        0b01000100, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 76 0x4c 'L'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 77 0x4d 'M'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b10000010, //
-        0b11000110, //
-        0b10101010, //
-        0b10010010, //
-        0b10010010, //
-        0b10000010, //
-        0b10000010, //
-        0b10000010, //
-        0b10000010, //
-        0b10000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 78 0x4e 'N'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01100010, //
-        0b01010010, //
-        0b01001010, //
-        0b01000110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 79 0x4f 'O'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 80 0x50 'P'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111100, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 81 0x51 'Q'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01001010, //
-        0b00111100, //
-        0b00000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 82 0x52 'R'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111100, //
-        0b01010000, //
-        0b01001000, //
-        0b01000100, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 83 0x53 'S'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000000, //
-        0b01000000, //
-        0b00111100, //
-        0b00000010, //
-        0b00000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 84 0x54 'T'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b11111110, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 85 0x55 'U'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 86 0x56 'V'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00100100, //
-        0b00100100, //
-        0b00100100, //
-        0b00011000, //
-        0b00011000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 87 0x57 'W'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b10000010, //
-        0b10000010, //
-        0b10000010, //
-        0b10000010, //
-        0b10000010, //
-        0b10010010, //
-        0b10010010, //
-        0b10101010, //
-        0b11000110, //
-        0b10000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 88 0x58 'X'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b00100100, //
-        0b00100100, //
-        0b00011000, //
-        0b00011000, //
-        0b00100100, //
-        0b00100100, //
-        0b01000010, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 89 0x59 'Y'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b10000010, //
-        0b10000010, //
-        0b01000100, //
-        0b01000100, //
-        0b00101000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 90 0x5a 'Z'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b00000010, //
-        0b00000010, //
-        0b00000100, //
-        0b00001000, //
-        0b00010000, //
-        0b00100000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 91 0x5b '['
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00100000, //
-        0b00111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 92 0x5c '\'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000000, //
-        0b01000000, //
-        0b00100000, //
-        0b00100000, //
-        0b00010000, //
-        0b00010000, //
-        0b00001000, //
-        0b00001000, //
-        0b00000100, //
-        0b00000100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 93 0x5d ']'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00111000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 94 0x5e '^'
-    [
-        0b00000000, //
-        0b00010000, //
-        0b00101000, //
-        0b01000100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 95 0x5f '_'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 96 0x60 '`'
-    [
-        0b00010000, //
-        0b00001000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 97 0x61 'a'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b00000010, //
-        0b00111110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 98 0x62 'b'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 99 0x63 'c'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 100 0x64 'd'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000010, //
-        0b00000010, //
-        0b00000010, //
-        0b00111110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 101 0x65 'e'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01111110, //
-        0b01000000, //
-        0b01000000, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 102 0x66 'f'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00001110, //
-        0b00010000, //
-        0b00010000, //
-        0b01111100, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 103 0x67 'g'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00111110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111110, //
-        0b00000010, //
-        0b00000010, //
-        0b00111100, //
-        0b00000000, //
-    ],
-    // 104 0x68 'h'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 105 0x69 'i'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00110000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 106 0x6a 'j'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000100, //
-        0b00000100, //
-        0b00000000, //
-        0b00001100, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b00000100, //
-        0b01000100, //
-        0b01000100, //
-        0b00111000, //
-        0b00000000, //
-    ],
-    // 107 0x6b 'k'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000010, //
-        0b01000100, //
-        0b01001000, //
-        0b01110000, //
-        0b01001000, //
-        0b01000100, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 108 0x6c 'l'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00110000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00111000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 109 0x6d 'm'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b11111100, //
-        0b10010010, //
-        0b10010010, //
-        0b10010010, //
-        0b10010010, //
-        0b10010010, //
-        0b10010010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 110 0x6e 'n'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 111 0x6f 'o'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 112 0x70 'p'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01111100, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01111100, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b00000000, //
-    ],
-    // 113 0x71 'q'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00111110, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111110, //
-        0b00000010, //
-        0b00000010, //
-        0b00000010, //
-        0b00000000, //
-    ],
-    // 114 0x72 'r'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01011110, //
-        0b01100000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b01000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 115 0x73 's'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00111110, //
-        0b01000000, //
-        0b01000000, //
-        0b00111100, //
-        0b00000010, //
-        0b00000010, //
-        0b01111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 116 0x74 't'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b01111100, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00001110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 117 0x75 'u'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 118 0x76 'v'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00100100, //
-        0b00100100, //
-        0b00011000, //
-        0b00011000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 119 0x77 'w'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b10000010, //
-        0b10000010, //
-        0b10010010, //
-        0b10010010, //
-        0b10010010, //
-        0b10010010, //
-        0b01111100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 120 0x78 'x'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b00100100, //
-        0b00011000, //
-        0b00100100, //
-        0b01000010, //
-        0b01000010, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 121 0x79 'y'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b01000010, //
-        0b00111110, //
-        0b00000010, //
-        0b00000010, //
-        0b00111100, //
-        0b00000000, //
-    ],
-    // 122 0x7a 'z'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b01111110, //
-        0b00000100, //
-        0b00001000, //
-        0b00010000, //
-        0b00100000, //
-        0b01000000, //
-        0b01111110, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 123 0x7b '{'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00001100, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00100000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00001100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 124 0x7c '|'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00010000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 125 0x7d '}'
-    [
-        0b00000000, //
-        0b00000000, //
-        0b00110000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00000100, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00001000, //
-        0b00110000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
-    // 126 0x7e '~'
-    [
-        0b00000000, //
-        0b01100010, //
-        0b10010010, //
-        0b10001100, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-        0b00000000, //
-    ],
+/// ASCII bitmaps from Terminus 8x16 regular PSF font, the other glyphs drawn on the same grid : Latin-1 Supplement,
+/// Latin Extended-A, box drawing, block elements, the euro sign and the replacement char
+static GLYPHS: [(char, [u8; CHAR_HEIGHT]); GLYPH_COUNT] = [
+    // U+0020 space
+    (
+        ' ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0021 exclamation mark
+    (
+        '!',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0022 quotation mark
+    (
+        '"',
+        [
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0023 number sign
+    (
+        '#',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00100100, //
+            0b01111110, //
+            0b00100100, //
+            0b00100100, //
+            0b01111110, //
+            0b00100100, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0024 dollar sign
+    (
+        '$',
+        [
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b10010010, //
+            0b10010000, //
+            0b10010000, //
+            0b01111100, //
+            0b00010010, //
+            0b00010010, //
+            0b10010010, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0025 percent sign
+    (
+        '%',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01100100, //
+            0b10010100, //
+            0b01101000, //
+            0b00001000, //
+            0b00010000, //
+            0b00010000, //
+            0b00100000, //
+            0b00101100, //
+            0b01010010, //
+            0b01001100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0026 ampersand
+    (
+        '&',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00100100, //
+            0b00011000, //
+            0b00110000, //
+            0b01001010, //
+            0b01000100, //
+            0b01000100, //
+            0b01000100, //
+            0b00111010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0027 apostrophe
+    (
+        '\'',
+        [
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0028 left parenthesis
+    (
+        '(',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00010000, //
+            0b00001000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0029 right parenthesis
+    (
+        ')',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+002A asterisk
+    (
+        '*',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00011000, //
+            0b01111110, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+002B plus sign
+    (
+        '+',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+002C comma
+    (
+        ',',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00100000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+002D hyphen-minus
+    (
+        '-',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+002E full stop
+    (
+        '.',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+002F solidus
+    (
+        '/',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000100, //
+            0b00000100, //
+            0b00001000, //
+            0b00001000, //
+            0b00010000, //
+            0b00010000, //
+            0b00100000, //
+            0b00100000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0030 digit zero
+    (
+        '0',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000110, //
+            0b01001010, //
+            0b01010010, //
+            0b01100010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0031 digit one
+    (
+        '1',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00011000, //
+            0b00101000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0032 digit two
+    (
+        '2',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b00000010, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0033 digit three
+    (
+        '3',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b00000010, //
+            0b00011100, //
+            0b00000010, //
+            0b00000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0034 digit four
+    (
+        '4',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000010, //
+            0b00000110, //
+            0b00001010, //
+            0b00010010, //
+            0b00100010, //
+            0b01000010, //
+            0b01111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0035 digit five
+    (
+        '5',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111100, //
+            0b00000010, //
+            0b00000010, //
+            0b00000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0036 digit six
+    (
+        '6',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011100, //
+            0b00100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0037 digit seven
+    (
+        '7',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00000100, //
+            0b00000100, //
+            0b00001000, //
+            0b00001000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0038 digit eight
+    (
+        '8',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0039 digit nine
+    (
+        '9',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00000100, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+003A colon
+    (
+        ':',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+003B semicolon
+    (
+        ';',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00100000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+003C less-than sign
+    (
+        '<',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00001000, //
+            0b00000100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+003D equals sign
+    (
+        '=',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+003E greater-than sign
+    (
+        '>',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00001000, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+003F question mark
+    (
+        '?',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000100, //
+            0b00001000, //
+            0b00001000, //
+            0b00000000, //
+            0b00001000, //
+            0b00001000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0040 commercial at
+    (
+        '@',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b10000010, //
+            0b10011110, //
+            0b10100010, //
+            0b10100010, //
+            0b10100010, //
+            0b10100110, //
+            0b10011010, //
+            0b10000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0041 latin capital letter a
+    (
+        'A',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0042 latin capital letter b
+    (
+        'B',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0043 latin capital letter c
+    (
+        'C',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0044 latin capital letter d
+    (
+        'D',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111000, //
+            0b01000100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000100, //
+            0b01111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0045 latin capital letter e
+    (
+        'E',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0046 latin capital letter f
+    (
+        'F',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0047 latin capital letter g
+    (
+        'G',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01001110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0048 latin capital letter h
+    (
+        'H',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0049 latin capital letter i
+    (
+        'I',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+004A latin capital letter j
+    (
+        'J',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001110, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b01000100, //
+            0b01000100, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+004B latin capital letter k
+    (
+        'K',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000100, //
+            0b01001000, //
+            0b01010000, //
+            0b01100000, //
+            0b01100000, //
+            0b01010000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+004C latin capital letter l
+    (
+        'L',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+004D latin capital letter m
+    (
+        'M',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b10000010, //
+            0b11000110, //
+            0b10101010, //
+            0b10010010, //
+            0b10010010, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+004E latin capital letter n
+    (
+        'N',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01100010, //
+            0b01010010, //
+            0b01001010, //
+            0b01000110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+004F latin capital letter o
+    (
+        'O',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0050 latin capital letter p
+    (
+        'P',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0051 latin capital letter q
+    (
+        'Q',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01001010, //
+            0b00111100, //
+            0b00000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0052 latin capital letter r
+    (
+        'R',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01010000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0053 latin capital letter s
+    (
+        'S',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0054 latin capital letter t
+    (
+        'T',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b11111110, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0055 latin capital letter u
+    (
+        'U',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0056 latin capital letter v
+    (
+        'V',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00100100, //
+            0b00100100, //
+            0b00100100, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0057 latin capital letter w
+    (
+        'W',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b10010010, //
+            0b10010010, //
+            0b10101010, //
+            0b11000110, //
+            0b10000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0058 latin capital letter x
+    (
+        'X',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00100100, //
+            0b00100100, //
+            0b00011000, //
+            0b00011000, //
+            0b00100100, //
+            0b00100100, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0059 latin capital letter y
+    (
+        'Y',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b01000100, //
+            0b01000100, //
+            0b00101000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+005A latin capital letter z
+    (
+        'Z',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+005B left square bracket
+    (
+        '[',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+005C reverse solidus
+    (
+        '\\',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00100000, //
+            0b00100000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001000, //
+            0b00001000, //
+            0b00000100, //
+            0b00000100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+005D right square bracket
+    (
+        ']',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+005E circumflex accent
+    (
+        '^',
+        [
+            0b00000000, //
+            0b00010000, //
+            0b00101000, //
+            0b01000100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+005F low line
+    (
+        '_',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0060 grave accent
+    (
+        '`',
+        [
+            0b00010000, //
+            0b00001000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0061 latin small letter a
+    (
+        'a',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0062 latin small letter b
+    (
+        'b',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0063 latin small letter c
+    (
+        'c',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0064 latin small letter d
+    (
+        'd',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000010, //
+            0b00000010, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0065 latin small letter e
+    (
+        'e',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0066 latin small letter f
+    (
+        'f',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001110, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0067 latin small letter g
+    (
+        'g',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+0068 latin small letter h
+    (
+        'h',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0069 latin small letter i
+    (
+        'i',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+006A latin small letter j
+    (
+        'j',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000100, //
+            0b00000100, //
+            0b00000000, //
+            0b00001100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b01000100, //
+            0b01000100, //
+            0b00111000, //
+            0b00000000, //
+        ],
+    ),
+    // U+006B latin small letter k
+    (
+        'k',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000100, //
+            0b01001000, //
+            0b01110000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+006C latin small letter l
+    (
+        'l',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+006D latin small letter m
+    (
+        'm',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111100, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+006E latin small letter n
+    (
+        'n',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+006F latin small letter o
+    (
+        'o',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0070 latin small letter p
+    (
+        'p',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0071 latin small letter q
+    (
+        'q',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00000010, //
+            0b00000000, //
+        ],
+    ),
+    // U+0072 latin small letter r
+    (
+        'r',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01011110, //
+            0b01100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0073 latin small letter s
+    (
+        's',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0074 latin small letter t
+    (
+        't',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0075 latin small letter u
+    (
+        'u',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0076 latin small letter v
+    (
+        'v',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00100100, //
+            0b00100100, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0077 latin small letter w
+    (
+        'w',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0078 latin small letter x
+    (
+        'x',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00100100, //
+            0b00011000, //
+            0b00100100, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0079 latin small letter y
+    (
+        'y',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+007A latin small letter z
+    (
+        'z',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+007B left curly bracket
+    (
+        '{',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00100000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+007C vertical line
+    (
+        '|',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+007D right curly bracket
+    (
+        '}',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00000100, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00001000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+007E tilde
+    (
+        '~',
+        [
+            0b00000000, //
+            0b01100010, //
+            0b10010010, //
+            0b10001100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A0 no-break space
+    (
+        '\u{a0}',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A1 inverted exclamation mark
+    (
+        '¡',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A2 cent sign
+    (
+        '¢',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00111100, //
+            0b01010010, //
+            0b01010000, //
+            0b01010000, //
+            0b01010000, //
+            0b01010010, //
+            0b00111100, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A3 pound sign
+    (
+        '£',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011100, //
+            0b00100010, //
+            0b00100000, //
+            0b00100000, //
+            0b11111000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A4 currency sign
+    (
+        '¤',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00100100, //
+            0b00100100, //
+            0b00111100, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A5 yen sign
+    (
+        '¥',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b10000010, //
+            0b01000100, //
+            0b00101000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A6 broken bar
+    (
+        '¦',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A7 section sign
+    (
+        '§',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A8 diaeresis
+    (
+        '¨',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00A9 copyright sign
+    (
+        '©',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b10011001, //
+            0b10100101, //
+            0b10100001, //
+            0b10100001, //
+            0b10100101, //
+            0b10011001, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00AA feminine ordinal indicator
+    (
+        'ª',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111000, //
+            0b00000100, //
+            0b00111100, //
+            0b01000100, //
+            0b00111100, //
+            0b00000000, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00AB left-pointing double angle quotation mark
+    (
+        '«',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010010, //
+            0b00100100, //
+            0b01001000, //
+            0b00100100, //
+            0b00010010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00AC not sign
+    (
+        '¬',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00AD soft hyphen
+    (
+        '\u{ad}',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00AE registered sign
+    (
+        '®',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b10111001, //
+            0b10100101, //
+            0b10111001, //
+            0b10101001, //
+            0b10100101, //
+            0b10000001, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00AF macron
+    (
+        '¯',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B0 degree sign
+    (
+        '°',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B1 plus-minus sign
+    (
+        '±',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B2 superscript two
+    (
+        '²',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01110000, //
+            0b00001000, //
+            0b00110000, //
+            0b01000000, //
+            0b01111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B3 superscript three
+    (
+        '³',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01110000, //
+            0b00001000, //
+            0b00110000, //
+            0b00001000, //
+            0b01110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B4 acute accent
+    (
+        '´',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B5 micro sign
+    (
+        'µ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000110, //
+            0b01111010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B6 pilcrow sign
+    (
+        '¶',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111110, //
+            0b01110100, //
+            0b01110100, //
+            0b01110100, //
+            0b00110100, //
+            0b00010100, //
+            0b00010100, //
+            0b00010100, //
+            0b00010100, //
+            0b00010100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B7 middle dot
+    (
+        '·',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B8 cedilla
+    (
+        '¸',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00B9 superscript one
+    (
+        '¹',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100000, //
+            0b01100000, //
+            0b00100000, //
+            0b00100000, //
+            0b01110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00BA masculine ordinal indicator
+    (
+        'º',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111000, //
+            0b01000100, //
+            0b01000100, //
+            0b01000100, //
+            0b00111000, //
+            0b00000000, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00BB right-pointing double angle quotation mark
+    (
+        '»',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01001000, //
+            0b00100100, //
+            0b00010010, //
+            0b00100100, //
+            0b01001000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00BC vulgar fraction one quarter
+    (
+        '¼',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b11000000, //
+            0b01000010, //
+            0b01000100, //
+            0b11101000, //
+            0b00010000, //
+            0b00100100, //
+            0b01001100, //
+            0b10010100, //
+            0b00011110, //
+            0b00000100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00BD vulgar fraction one half
+    (
+        '½',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b11000000, //
+            0b01000010, //
+            0b01000100, //
+            0b11101000, //
+            0b00010000, //
+            0b00101100, //
+            0b01010010, //
+            0b10000010, //
+            0b00001100, //
+            0b00010000, //
+            0b00011110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00BE vulgar fraction three quarters
+    (
+        '¾',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b11000000, //
+            0b00100000, //
+            0b01000010, //
+            0b00100100, //
+            0b11001000, //
+            0b00010000, //
+            0b00100100, //
+            0b01001100, //
+            0b10010100, //
+            0b00011110, //
+            0b00000100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00BF inverted question mark
+    (
+        '¿',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00001000, //
+            0b00000000, //
+            0b00001000, //
+            0b00001000, //
+            0b00000100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C0 latin capital letter a with grave
+    (
+        'À',
+        [
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C1 latin capital letter a with acute
+    (
+        'Á',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C2 latin capital letter a with circumflex
+    (
+        'Â',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C3 latin capital letter a with tilde
+    (
+        'Ã',
+        [
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C4 latin capital letter a with diaeresis
+    (
+        'Ä',
+        [
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C5 latin capital letter a with ring above
+    (
+        'Å',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00011000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C6 latin capital letter ae
+    (
+        'Æ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111111, //
+            0b01001000, //
+            0b10001000, //
+            0b10001000, //
+            0b10001000, //
+            0b11111110, //
+            0b10001000, //
+            0b10001000, //
+            0b10001000, //
+            0b10001111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C7 latin capital letter c with cedilla
+    (
+        'Ç',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C8 latin capital letter e with grave
+    (
+        'È',
+        [
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00C9 latin capital letter e with acute
+    (
+        'É',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00CA latin capital letter e with circumflex
+    (
+        'Ê',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00CB latin capital letter e with diaeresis
+    (
+        'Ë',
+        [
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00CC latin capital letter i with grave
+    (
+        'Ì',
+        [
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00CD latin capital letter i with acute
+    (
+        'Í',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00CE latin capital letter i with circumflex
+    (
+        'Î',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00CF latin capital letter i with diaeresis
+    (
+        'Ï',
+        [
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D0 latin capital letter eth
+    (
+        'Ð',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111000, //
+            0b01000100, //
+            0b01000010, //
+            0b01000010, //
+            0b11100010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000100, //
+            0b01111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D1 latin capital letter n with tilde
+    (
+        'Ñ',
+        [
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01100010, //
+            0b01010010, //
+            0b01001010, //
+            0b01000110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D2 latin capital letter o with grave
+    (
+        'Ò',
+        [
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D3 latin capital letter o with acute
+    (
+        'Ó',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D4 latin capital letter o with circumflex
+    (
+        'Ô',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D5 latin capital letter o with tilde
+    (
+        'Õ',
+        [
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D6 latin capital letter o with diaeresis
+    (
+        'Ö',
+        [
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D7 multiplication sign
+    (
+        '×',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000100, //
+            0b00101000, //
+            0b00010000, //
+            0b00101000, //
+            0b01000100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D8 latin capital letter o with stroke
+    (
+        'Ø',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111101, //
+            0b01000110, //
+            0b01000110, //
+            0b01001010, //
+            0b01001010, //
+            0b01010010, //
+            0b01010010, //
+            0b01100010, //
+            0b01100010, //
+            0b10111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00D9 latin capital letter u with grave
+    (
+        'Ù',
+        [
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00DA latin capital letter u with acute
+    (
+        'Ú',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00DB latin capital letter u with circumflex
+    (
+        'Û',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00DC latin capital letter u with diaeresis
+    (
+        'Ü',
+        [
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00DD latin capital letter y with acute
+    (
+        'Ý',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b01000100, //
+            0b00101000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00DE latin capital letter thorn
+    (
+        'Þ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00DF latin small letter sharp s
+    (
+        'ß',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111000, //
+            0b01000100, //
+            0b01000100, //
+            0b01001000, //
+            0b01011100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01011100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E0 latin small letter a with grave
+    (
+        'à',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E1 latin small letter a with acute
+    (
+        'á',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E2 latin small letter a with circumflex
+    (
+        'â',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E3 latin small letter a with tilde
+    (
+        'ã',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E4 latin small letter a with diaeresis
+    (
+        'ä',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E5 latin small letter a with ring above
+    (
+        'å',
+        [
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E6 latin small letter ae
+    (
+        'æ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01101100, //
+            0b00010010, //
+            0b01111110, //
+            0b10010000, //
+            0b10010000, //
+            0b10010010, //
+            0b01101100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E7 latin small letter c with cedilla
+    (
+        'ç',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E8 latin small letter e with grave
+    (
+        'è',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00E9 latin small letter e with acute
+    (
+        'é',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00EA latin small letter e with circumflex
+    (
+        'ê',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00EB latin small letter e with diaeresis
+    (
+        'ë',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00EC latin small letter i with grave
+    (
+        'ì',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00ED latin small letter i with acute
+    (
+        'í',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00EE latin small letter i with circumflex
+    (
+        'î',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00EF latin small letter i with diaeresis
+    (
+        'ï',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F0 latin small letter eth
+    (
+        'ð',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00101000, //
+            0b00010000, //
+            0b00101000, //
+            0b00000100, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F1 latin small letter n with tilde
+    (
+        'ñ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F2 latin small letter o with grave
+    (
+        'ò',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F3 latin small letter o with acute
+    (
+        'ó',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F4 latin small letter o with circumflex
+    (
+        'ô',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F5 latin small letter o with tilde
+    (
+        'õ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F6 latin small letter o with diaeresis
+    (
+        'ö',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F7 division sign
+    (
+        '÷',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111100, //
+            0b00000000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F8 latin small letter o with stroke
+    (
+        'ø',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111101, //
+            0b01000110, //
+            0b01001010, //
+            0b01001010, //
+            0b01010010, //
+            0b01100010, //
+            0b10111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00F9 latin small letter u with grave
+    (
+        'ù',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00FA latin small letter u with acute
+    (
+        'ú',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00FB latin small letter u with circumflex
+    (
+        'û',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00FC latin small letter u with diaeresis
+    (
+        'ü',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00FD latin small letter y with acute
+    (
+        'ý',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+00FE latin small letter thorn
+    (
+        'þ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+00FF latin small letter y with diaeresis
+    (
+        'ÿ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+0100 latin capital letter a with macron
+    (
+        'Ā',
+        [
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0101 latin small letter a with macron
+    (
+        'ā',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0102 latin capital letter a with breve
+    (
+        'Ă',
+        [
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0103 latin small letter a with breve
+    (
+        'ă',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0104 latin capital letter a with ogonek
+    (
+        'Ą',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0105 latin small letter a with ogonek
+    (
+        'ą',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0106 latin capital letter c with acute
+    (
+        'Ć',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0107 latin small letter c with acute
+    (
+        'ć',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0108 latin capital letter c with circumflex
+    (
+        'Ĉ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0109 latin small letter c with circumflex
+    (
+        'ĉ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+010A latin capital letter c with dot above
+    (
+        'Ċ',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+010B latin small letter c with dot above
+    (
+        'ċ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+010C latin capital letter c with caron
+    (
+        'Č',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+010D latin small letter c with caron
+    (
+        'č',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+010E latin capital letter d with caron
+    (
+        'Ď',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01111000, //
+            0b01000100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000100, //
+            0b01111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+010F latin small letter d with caron
+    (
+        'ď',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000101, //
+            0b00000101, //
+            0b00000100, //
+            0b01111100, //
+            0b10000100, //
+            0b10000100, //
+            0b10000100, //
+            0b10000100, //
+            0b10000100, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0110 latin capital letter d with stroke
+    (
+        'Đ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111000, //
+            0b01000100, //
+            0b01000010, //
+            0b01000010, //
+            0b11100010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000100, //
+            0b01111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0111 latin small letter d with stroke
+    (
+        'đ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000010, //
+            0b00001111, //
+            0b00000010, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0112 latin capital letter e with macron
+    (
+        'Ē',
+        [
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0113 latin small letter e with macron
+    (
+        'ē',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0114 latin capital letter e with breve
+    (
+        'Ĕ',
+        [
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0115 latin small letter e with breve
+    (
+        'ĕ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0116 latin capital letter e with dot above
+    (
+        'Ė',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0117 latin small letter e with dot above
+    (
+        'ė',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0118 latin capital letter e with ogonek
+    (
+        'Ę',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0119 latin small letter e with ogonek
+    (
+        'ę',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+011A latin capital letter e with caron
+    (
+        'Ě',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b01111000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+011B latin small letter e with caron
+    (
+        'ě',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+011C latin capital letter g with circumflex
+    (
+        'Ĝ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01001110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+011D latin small letter g with circumflex
+    (
+        'ĝ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+011E latin capital letter g with breve
+    (
+        'Ğ',
+        [
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01001110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+011F latin small letter g with breve
+    (
+        'ğ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+0120 latin capital letter g with dot above
+    (
+        'Ġ',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01001110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0121 latin small letter g with dot above
+    (
+        'ġ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+0122 latin capital letter g with cedilla
+    (
+        'Ģ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b01001110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0123 latin small letter g with cedilla
+    (
+        'ģ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+0124 latin capital letter h with circumflex
+    (
+        'Ĥ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0125 latin small letter h with circumflex
+    (
+        'ĥ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0126 latin capital letter h with stroke
+    (
+        'Ħ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b11111111, //
+            0b01000010, //
+            0b01111110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0127 latin small letter h with stroke
+    (
+        'ħ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b11110000, //
+            0b01000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0128 latin capital letter i with tilde
+    (
+        'Ĩ',
+        [
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0129 latin small letter i with tilde
+    (
+        'ĩ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+012A latin capital letter i with macron
+    (
+        'Ī',
+        [
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+012B latin small letter i with macron
+    (
+        'ī',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+012C latin capital letter i with breve
+    (
+        'Ĭ',
+        [
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+012D latin small letter i with breve
+    (
+        'ĭ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+012E latin capital letter i with ogonek
+    (
+        'Į',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+012F latin small letter i with ogonek
+    (
+        'į',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0130 latin capital letter i with dot above
+    (
+        'İ',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0131 latin small letter dotless i
+    (
+        'ı',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0132 latin capital ligature ij
+    (
+        'Ĳ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b11100010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01010010, //
+            0b11101100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0133 latin small ligature ij
+    (
+        'ĳ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b11000110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b11100010, //
+            0b00000010, //
+            0b00000010, //
+            0b00001100, //
+            0b00000000, //
+        ],
+    ),
+    // U+0134 latin capital letter j with circumflex
+    (
+        'Ĵ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00001110, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b01000100, //
+            0b01000100, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0135 latin small letter j with circumflex
+    (
+        'ĵ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001100, //
+            0b00010010, //
+            0b00000000, //
+            0b00001100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b00000100, //
+            0b01000100, //
+            0b01000100, //
+            0b00111000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0136 latin capital letter k with cedilla
+    (
+        'Ķ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000100, //
+            0b01001000, //
+            0b01010000, //
+            0b01100000, //
+            0b01100000, //
+            0b01010000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0137 latin small letter k with cedilla
+    (
+        'ķ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000010, //
+            0b01000100, //
+            0b01001000, //
+            0b01110000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0138 latin small letter kra
+    (
+        'ĸ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000100, //
+            0b01001000, //
+            0b01110000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0139 latin capital letter l with acute
+    (
+        'Ĺ',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+013A latin small letter l with acute
+    (
+        'ĺ',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+013B latin capital letter l with cedilla
+    (
+        'Ļ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+013C latin small letter l with cedilla
+    (
+        'ļ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+013D latin capital letter l with caron
+    (
+        'Ľ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01001000, //
+            0b01001000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+013E latin small letter l with caron
+    (
+        'ľ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01100100, //
+            0b00100100, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b00100000, //
+            0b01110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+013F latin capital letter l with middle dot
+    (
+        'Ŀ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01001000, //
+            0b01001000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0140 latin small letter l with middle dot
+    (
+        'ŀ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010100, //
+            0b00010100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0141 latin capital letter l with stroke
+    (
+        'Ł',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01010000, //
+            0b01100000, //
+            0b11000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0142 latin small letter l with stroke
+    (
+        'ł',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010100, //
+            0b00011000, //
+            0b00110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0143 latin capital letter n with acute
+    (
+        'Ń',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01100010, //
+            0b01010010, //
+            0b01001010, //
+            0b01000110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0144 latin small letter n with acute
+    (
+        'ń',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0145 latin capital letter n with cedilla
+    (
+        'Ņ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01100010, //
+            0b01010010, //
+            0b01001010, //
+            0b01000110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0146 latin small letter n with cedilla
+    (
+        'ņ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0147 latin capital letter n with caron
+    (
+        'Ň',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01100010, //
+            0b01010010, //
+            0b01001010, //
+            0b01000110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0148 latin small letter n with caron
+    (
+        'ň',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0149 latin small letter n preceded by apostrophe
+    (
+        'ŉ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b10000000, //
+            0b10000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+014A latin capital letter eng
+    (
+        'Ŋ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01100010, //
+            0b01010010, //
+            0b01001010, //
+            0b01000110, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000010, //
+            0b00001100, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+014B latin small letter eng
+    (
+        'ŋ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00000010, //
+            0b00000010, //
+            0b00001100, //
+            0b00000000, //
+        ],
+    ),
+    // U+014C latin capital letter o with macron
+    (
+        'Ō',
+        [
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+014D latin small letter o with macron
+    (
+        'ō',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+014E latin capital letter o with breve
+    (
+        'Ŏ',
+        [
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+014F latin small letter o with breve
+    (
+        'ŏ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0150 latin capital letter o with double acute
+    (
+        'Ő',
+        [
+            0b00010010, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0151 latin small letter o with double acute
+    (
+        'ő',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010010, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0152 latin capital ligature oe
+    (
+        'Œ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111111, //
+            0b01001000, //
+            0b01001000, //
+            0b01001000, //
+            0b01001110, //
+            0b01001000, //
+            0b01001000, //
+            0b01001000, //
+            0b01001000, //
+            0b00111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0153 latin small ligature oe
+    (
+        'œ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01101100, //
+            0b10010010, //
+            0b10010010, //
+            0b10011110, //
+            0b10010000, //
+            0b10010000, //
+            0b01101110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0154 latin capital letter r with acute
+    (
+        'Ŕ',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01010000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0155 latin small letter r with acute
+    (
+        'ŕ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01011110, //
+            0b01100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0156 latin capital letter r with cedilla
+    (
+        'Ŗ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01010000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0157 latin small letter r with cedilla
+    (
+        'ŗ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01011110, //
+            0b01100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0158 latin capital letter r with caron
+    (
+        'Ř',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01111100, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01111100, //
+            0b01010000, //
+            0b01001000, //
+            0b01000100, //
+            0b01000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0159 latin small letter r with caron
+    (
+        'ř',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01011110, //
+            0b01100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+015A latin capital letter s with acute
+    (
+        'Ś',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+015B latin small letter s with acute
+    (
+        'ś',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+015C latin capital letter s with circumflex
+    (
+        'Ŝ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+015D latin small letter s with circumflex
+    (
+        'ŝ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b00111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+015E latin capital letter s with cedilla
+    (
+        'Ş',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+015F latin small letter s with cedilla
+    (
+        'ş',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01111100, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0160 latin capital letter s with caron
+    (
+        'Š',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b00111100, //
+            0b01000010, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0161 latin small letter s with caron
+    (
+        'š',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b00111110, //
+            0b01000000, //
+            0b01000000, //
+            0b00111100, //
+            0b00000010, //
+            0b00000010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0162 latin capital letter t with cedilla
+    (
+        'Ţ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b11111110, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0163 latin small letter t with cedilla
+    (
+        'ţ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001110, //
+            0b00010000, //
+            0b00110000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0164 latin capital letter t with caron
+    (
+        'Ť',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b11111110, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0165 latin small letter t with caron
+    (
+        'ť',
+        [
+            0b00000000, //
+            0b00000010, //
+            0b00010010, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0166 latin capital letter t with stroke
+    (
+        'Ŧ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b11111110, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0167 latin small letter t with stroke
+    (
+        'ŧ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b01111100, //
+            0b00010000, //
+            0b00010000, //
+            0b00111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0168 latin capital letter u with tilde
+    (
+        'Ũ',
+        [
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0169 latin small letter u with tilde
+    (
+        'ũ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00110010, //
+            0b01001100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+016A latin capital letter u with macron
+    (
+        'Ū',
+        [
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+016B latin small letter u with macron
+    (
+        'ū',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+016C latin capital letter u with breve
+    (
+        'Ŭ',
+        [
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+016D latin small letter u with breve
+    (
+        'ŭ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+016E latin capital letter u with ring above
+    (
+        'Ů',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00011000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+016F latin small letter u with ring above
+    (
+        'ů',
+        [
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0170 latin capital letter u with double acute
+    (
+        'Ű',
+        [
+            0b00010010, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0171 latin small letter u with double acute
+    (
+        'ű',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010010, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0172 latin capital letter u with ogonek
+    (
+        'Ų',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111100, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0173 latin small letter u with ogonek
+    (
+        'ų',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00001000, //
+            0b00000110, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0174 latin capital letter w with circumflex
+    (
+        'Ŵ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b10000010, //
+            0b10010010, //
+            0b10010010, //
+            0b10101010, //
+            0b11000110, //
+            0b10000010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0175 latin small letter w with circumflex
+    (
+        'ŵ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b10010010, //
+            0b01111100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0176 latin capital letter y with circumflex
+    (
+        'Ŷ',
+        [
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b01000100, //
+            0b00101000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0177 latin small letter y with circumflex
+    (
+        'ŷ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00100100, //
+            0b00000000, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b01000010, //
+            0b00111110, //
+            0b00000010, //
+            0b00000010, //
+            0b00111100, //
+            0b00000000, //
+        ],
+    ),
+    // U+0178 latin capital letter y with diaeresis
+    (
+        'Ÿ',
+        [
+            0b00100100, //
+            0b00100100, //
+            0b00000000, //
+            0b10000010, //
+            0b10000010, //
+            0b01000100, //
+            0b00101000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+0179 latin capital letter z with acute
+    (
+        'Ź',
+        [
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000010, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+017A latin small letter z with acute
+    (
+        'ź',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+017B latin capital letter z with dot above
+    (
+        'Ż',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000010, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+017C latin small letter z with dot above
+    (
+        'ż',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+017D latin capital letter z with caron
+    (
+        'Ž',
+        [
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000010, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+017E latin small letter z with caron
+    (
+        'ž',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00100100, //
+            0b00011000, //
+            0b00000000, //
+            0b01111110, //
+            0b00000100, //
+            0b00001000, //
+            0b00010000, //
+            0b00100000, //
+            0b01000000, //
+            0b01111110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+017F latin small letter long s
+    (
+        'ſ',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00001110, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+20AC euro sign
+    (
+        '€',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011100, //
+            0b00100010, //
+            0b01000000, //
+            0b11110000, //
+            0b01000000, //
+            0b11110000, //
+            0b01000000, //
+            0b01000000, //
+            0b00100010, //
+            0b00011100, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2500 box drawings light horizontal
+    (
+        '─',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2501 box drawings heavy horizontal
+    (
+        '━',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2502 box drawings light vertical
+    (
+        '│',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2503 box drawings heavy vertical
+    (
+        '┃',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2504 box drawings light triple dash horizontal
+    (
+        '┄',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11011010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2505 box drawings heavy triple dash horizontal
+    (
+        '┅',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11011010, //
+            0b11011010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2506 box drawings light triple dash vertical
+    (
+        '┆',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2507 box drawings heavy triple dash vertical
+    (
+        '┇',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2508 box drawings light quadruple dash horizontal
+    (
+        '┈',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b10101010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2509 box drawings heavy quadruple dash horizontal
+    (
+        '┉',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b10101010, //
+            0b10101010, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+250A box drawings light quadruple dash vertical
+    (
+        '┊',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+        ],
+    ),
+    // U+250B box drawings heavy quadruple dash vertical
+    (
+        '┋',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+        ],
+    ),
+    // U+250C box drawings light down and right
+    (
+        '┌',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+250D box drawings down light and right heavy
+    (
+        '┍',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011111, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+250E box drawings down heavy and right light
+    (
+        '┎',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+250F box drawings heavy down and right
+    (
+        '┏',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011111, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2510 box drawings light down and left
+    (
+        '┐',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2511 box drawings down light and left heavy
+    (
+        '┑',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11110000, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2512 box drawings down heavy and left light
+    (
+        '┒',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2513 box drawings heavy down and left
+    (
+        '┓',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111000, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2514 box drawings light up and right
+    (
+        '└',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2515 box drawings up light and right heavy
+    (
+        '┕',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2516 box drawings up heavy and right light
+    (
+        '┖',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2517 box drawings heavy up and right
+    (
+        '┗',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011111, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2518 box drawings light up and left
+    (
+        '┘',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2519 box drawings up light and left heavy
+    (
+        '┙',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11110000, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+251A box drawings up heavy and left light
+    (
+        '┚',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+251B box drawings heavy up and left
+    (
+        '┛',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111000, //
+            0b11111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+251C box drawings light vertical and right
+    (
+        '├',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+251D box drawings vertical light and right heavy
+    (
+        '┝',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+251E box drawings up heavy and right down light
+    (
+        '┞',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+251F box drawings down heavy and right up light
+    (
+        '┟',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2520 box drawings vertical heavy and right light
+    (
+        '┠',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2521 box drawings down light and right up heavy
+    (
+        '┡',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011111, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2522 box drawings up light and right down heavy
+    (
+        '┢',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2523 box drawings heavy vertical and right
+    (
+        '┣',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011111, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2524 box drawings light vertical and left
+    (
+        '┤',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2525 box drawings vertical light and left heavy
+    (
+        '┥',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11110000, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2526 box drawings up heavy and left down light
+    (
+        '┦',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2527 box drawings down heavy and left up light
+    (
+        '┧',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2528 box drawings vertical heavy and left light
+    (
+        '┨',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2529 box drawings down light and left up heavy
+    (
+        '┩',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111000, //
+            0b11111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+252A box drawings up light and left down heavy
+    (
+        '┪',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111000, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+252B box drawings heavy vertical and left
+    (
+        '┫',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111000, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+252C box drawings light down and horizontal
+    (
+        '┬',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+252D box drawings left heavy and right down light
+    (
+        '┭',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+252E box drawings right heavy and left down light
+    (
+        '┮',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+252F box drawings down light and horizontal heavy
+    (
+        '┯',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2530 box drawings down heavy and horizontal light
+    (
+        '┰',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2531 box drawings right light and left down heavy
+    (
+        '┱',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2532 box drawings left light and right down heavy
+    (
+        '┲',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2533 box drawings heavy down and horizontal
+    (
+        '┳',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2534 box drawings light up and horizontal
+    (
+        '┴',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2535 box drawings left heavy and right up light
+    (
+        '┵',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2536 box drawings right heavy and left up light
+    (
+        '┶',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2537 box drawings up light and horizontal heavy
+    (
+        '┷',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2538 box drawings up heavy and horizontal light
+    (
+        '┸',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2539 box drawings right light and left up heavy
+    (
+        '┹',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b11111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+253A box drawings left light and right up heavy
+    (
+        '┺',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+253B box drawings heavy up and horizontal
+    (
+        '┻',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+253C box drawings light vertical and horizontal
+    (
+        '┼',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+253D box drawings left heavy and right vertical light
+    (
+        '┽',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+253E box drawings right heavy and left vertical light
+    (
+        '┾',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+253F box drawings vertical light and horizontal heavy
+    (
+        '┿',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2540 box drawings up heavy and down horizontal light
+    (
+        '╀',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2541 box drawings down heavy and up horizontal light
+    (
+        '╁',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2542 box drawings vertical heavy and horizontal light
+    (
+        '╂',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2543 box drawings left up heavy and right down light
+    (
+        '╃',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b11111000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2544 box drawings right up heavy and left down light
+    (
+        '╄',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2545 box drawings left down heavy and right up light
+    (
+        '╅',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2546 box drawings right down heavy and left up light
+    (
+        '╆',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2547 box drawings down light and up horizontal heavy
+    (
+        '╇',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2548 box drawings up light and down horizontal heavy
+    (
+        '╈',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b11111111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+2549 box drawings right light and left vertical heavy
+    (
+        '╉',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b11111000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+254A box drawings left light and right vertical heavy
+    (
+        '╊',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b00011111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+254B box drawings heavy vertical and horizontal
+    (
+        '╋',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b11111111, //
+            0b11111111, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+254C box drawings light double dash horizontal
+    (
+        '╌',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11101110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+254D box drawings heavy double dash horizontal
+    (
+        '╍',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11101110, //
+            0b11101110, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+254E box drawings light double dash vertical
+    (
+        '╎',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+254F box drawings heavy double dash vertical
+    (
+        '╏',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2550 box drawings double horizontal
+    (
+        '═',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00000000, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2551 box drawings double vertical
+    (
+        '║',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2552 box drawings down single and right double
+    (
+        '╒',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011111, //
+            0b00010000, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2553 box drawings down double and right single
+    (
+        '╓',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2554 box drawings double down and right
+    (
+        '╔',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00111111, //
+            0b00100000, //
+            0b00101111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2555 box drawings down single and left double
+    (
+        '╕',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11110000, //
+            0b00010000, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2556 box drawings down double and left single
+    (
+        '╖',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2557 box drawings double down and left
+    (
+        '╗',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111000, //
+            0b00001000, //
+            0b11101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2558 box drawings up single and right double
+    (
+        '╘',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00010000, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2559 box drawings up double and right single
+    (
+        '╙',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+255A box drawings double up and right
+    (
+        '╚',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101111, //
+            0b00100000, //
+            0b00111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+255B box drawings up single and left double
+    (
+        '╛',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11110000, //
+            0b00010000, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+255C box drawings up double and left single
+    (
+        '╜',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+255D box drawings double up and left
+    (
+        '╝',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11101000, //
+            0b00001000, //
+            0b11111000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+255E box drawings vertical single and right double
+    (
+        '╞',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011111, //
+            0b00010000, //
+            0b00011111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+255F box drawings vertical double and right single
+    (
+        '╟',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2560 box drawings double vertical and right
+    (
+        '╠',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101111, //
+            0b00100000, //
+            0b00101111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2561 box drawings vertical single and left double
+    (
+        '╡',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11110000, //
+            0b00010000, //
+            0b11110000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2562 box drawings vertical double and left single
+    (
+        '╢',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2563 box drawings double vertical and left
+    (
+        '╣',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11101000, //
+            0b00001000, //
+            0b11101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2564 box drawings down single and horizontal double
+    (
+        '╤',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00000000, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2565 box drawings down double and horizontal single
+    (
+        '╥',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2566 box drawings double down and horizontal
+    (
+        '╦',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00000000, //
+            0b11101111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+2567 box drawings up single and horizontal double
+    (
+        '╧',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00000000, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2568 box drawings up double and horizontal single
+    (
+        '╨',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2569 box drawings double up and horizontal
+    (
+        '╩',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11101111, //
+            0b00000000, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+256A box drawings vertical single and horizontal double
+    (
+        '╪',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b11111111, //
+            0b00010000, //
+            0b11111111, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+256B box drawings vertical double and horizontal single
+    (
+        '╫',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11111111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+256C box drawings double vertical and horizontal
+    (
+        '╬',
+        [
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b11101111, //
+            0b00000000, //
+            0b11101111, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+            0b00101000, //
+        ],
+    ),
+    // U+256D box drawings light arc down and right
+    (
+        '╭',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000111, //
+            0b00001000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+256E box drawings light arc down and left
+    (
+        '╮',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11000000, //
+            0b00100000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+256F box drawings light arc up and left
+    (
+        '╯',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00100000, //
+            0b11000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2570 box drawings light arc up and right
+    (
+        '╰',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001000, //
+            0b00000111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2571 box drawings light diagonal upper right to lower left
+    (
+        '╱',
+        [
+            0b00000001, //
+            0b00000001, //
+            0b00000010, //
+            0b00000010, //
+            0b00000100, //
+            0b00000100, //
+            0b00001000, //
+            0b00001000, //
+            0b00010000, //
+            0b00010000, //
+            0b00100000, //
+            0b00100000, //
+            0b01000000, //
+            0b01000000, //
+            0b10000000, //
+            0b10000000, //
+        ],
+    ),
+    // U+2572 box drawings light diagonal upper left to lower right
+    (
+        '╲',
+        [
+            0b10000000, //
+            0b10000000, //
+            0b01000000, //
+            0b01000000, //
+            0b00100000, //
+            0b00100000, //
+            0b00010000, //
+            0b00010000, //
+            0b00001000, //
+            0b00001000, //
+            0b00000100, //
+            0b00000100, //
+            0b00000010, //
+            0b00000010, //
+            0b00000001, //
+            0b00000001, //
+        ],
+    ),
+    // U+2573 box drawings light diagonal cross
+    (
+        '╳',
+        [
+            0b10000001, //
+            0b10000001, //
+            0b01000010, //
+            0b01000010, //
+            0b00100100, //
+            0b00100100, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00100100, //
+            0b00100100, //
+            0b01000010, //
+            0b01000010, //
+            0b10000001, //
+            0b10000001, //
+        ],
+    ),
+    // U+2574 box drawings light left
+    (
+        '╴',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2575 box drawings light up
+    (
+        '╵',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2576 box drawings light right
+    (
+        '╶',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2577 box drawings light down
+    (
+        '╷',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2578 box drawings heavy left
+    (
+        '╸',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11110000, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2579 box drawings heavy up
+    (
+        '╹',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+257A box drawings heavy right
+    (
+        '╺',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011111, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+257B box drawings heavy down
+    (
+        '╻',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+257C box drawings light left and heavy right
+    (
+        '╼',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b00011111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+257D box drawings light up and heavy down
+    (
+        '╽',
+        [
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+        ],
+    ),
+    // U+257E box drawings heavy left and light right
+    (
+        '╾',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+257F box drawings heavy up and light down
+    (
+        '╿',
+        [
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00011000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+            0b00010000, //
+        ],
+    ),
+    // U+2580 upper half block
+    (
+        '▀',
+        [
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2581 lower one eighth block
+    (
+        '▁',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2582 lower one quarter block
+    (
+        '▂',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2583 lower three eighths block
+    (
+        '▃',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2584 lower half block
+    (
+        '▄',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2585 lower five eighths block
+    (
+        '▅',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2586 lower three quarters block
+    (
+        '▆',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2587 lower seven eighths block
+    (
+        '▇',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2588 full block
+    (
+        '█',
+        [
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+2589 left seven eighths block
+    (
+        '▉',
+        [
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+            0b11111110, //
+        ],
+    ),
+    // U+258A left three quarters block
+    (
+        '▊',
+        [
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+            0b11111100, //
+        ],
+    ),
+    // U+258B left five eighths block
+    (
+        '▋',
+        [
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+            0b11111000, //
+        ],
+    ),
+    // U+258C left half block
+    (
+        '▌',
+        [
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+        ],
+    ),
+    // U+258D left three eighths block
+    (
+        '▍',
+        [
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+            0b11100000, //
+        ],
+    ),
+    // U+258E left one quarter block
+    (
+        '▎',
+        [
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+            0b11000000, //
+        ],
+    ),
+    // U+258F left one eighth block
+    (
+        '▏',
+        [
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+            0b10000000, //
+        ],
+    ),
+    // U+2590 right half block
+    (
+        '▐',
+        [
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+        ],
+    ),
+    // U+2591 light shade
+    (
+        '░',
+        [
+            0b10001000, //
+            0b00100010, //
+            0b10001000, //
+            0b00100010, //
+            0b10001000, //
+            0b00100010, //
+            0b10001000, //
+            0b00100010, //
+            0b10001000, //
+            0b00100010, //
+            0b10001000, //
+            0b00100010, //
+            0b10001000, //
+            0b00100010, //
+            0b10001000, //
+            0b00100010, //
+        ],
+    ),
+    // U+2592 medium shade
+    (
+        '▒',
+        [
+            0b10101010, //
+            0b01010101, //
+            0b10101010, //
+            0b01010101, //
+            0b10101010, //
+            0b01010101, //
+            0b10101010, //
+            0b01010101, //
+            0b10101010, //
+            0b01010101, //
+            0b10101010, //
+            0b01010101, //
+            0b10101010, //
+            0b01010101, //
+            0b10101010, //
+            0b01010101, //
+        ],
+    ),
+    // U+2593 dark shade
+    (
+        '▓',
+        [
+            0b01110111, //
+            0b11011101, //
+            0b01110111, //
+            0b11011101, //
+            0b01110111, //
+            0b11011101, //
+            0b01110111, //
+            0b11011101, //
+            0b01110111, //
+            0b11011101, //
+            0b01110111, //
+            0b11011101, //
+            0b01110111, //
+            0b11011101, //
+            0b01110111, //
+            0b11011101, //
+        ],
+    ),
+    // U+2594 upper one eighth block
+    (
+        '▔',
+        [
+            0b11111111, //
+            0b11111111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2595 right one eighth block
+    (
+        '▕',
+        [
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+            0b00000001, //
+        ],
+    ),
+    // U+2596 quadrant lower left
+    (
+        '▖',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+        ],
+    ),
+    // U+2597 quadrant lower right
+    (
+        '▗',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+        ],
+    ),
+    // U+2598 quadrant upper left
+    (
+        '▘',
+        [
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+2599 quadrant upper left and lower left and lower right
+    (
+        '▙',
+        [
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+259A quadrant upper left and lower right
+    (
+        '▚',
+        [
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+        ],
+    ),
+    // U+259B quadrant upper left and upper right and lower left
+    (
+        '▛',
+        [
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+        ],
+    ),
+    // U+259C quadrant upper left and upper right and lower right
+    (
+        '▜',
+        [
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+        ],
+    ),
+    // U+259D quadrant upper right
+    (
+        '▝',
+        [
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
+    // U+259E quadrant upper right and lower left
+    (
+        '▞',
+        [
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+            0b11110000, //
+        ],
+    ),
+    // U+259F quadrant upper right and lower left and lower right
+    (
+        '▟',
+        [
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b00001111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+            0b11111111, //
+        ],
+    ),
+    // U+FFFD replacement character
+    (
+        '�',
+        [
+            0b00000000, //
+            0b00000000, //
+            0b00011000, //
+            0b00111100, //
+            0b01100110, //
+            0b11011011, //
+            0b11111011, //
+            0b11110111, //
+            0b11101111, //
+            0b11101111, //
+            0b11111111, //
+            0b01101110, //
+            0b00111100, //
+            0b00011000, //
+            0b00000000, //
+            0b00000000, //
+        ],
+    ),
 ];
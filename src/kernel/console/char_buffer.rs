@@ -1,7 +1,16 @@
-use crate::kernel::console::char_bitmaps::{CharBit, CharBitMap, CHAR_RESOLUTION};
-use crate::kernel::native_graphics::{FrameBuffer, Pixel, PixelPosition, Resolution};
+use crate::kernel::console::char_bitmaps::CharBit;
+use crate::kernel::console::cursor::CursorShape;
+use crate::kernel::console::font::{Font, FontScale};
+use crate::kernel::console::scrollback::Scrollback;
+use crate::kernel::native_graphics::{
+    FrameBuffer, Pixel, PixelPosition, PixelRectangle, Resolution,
+};
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+use core::mem;
+use core::ops::Range;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(super) struct CharColors {
     pub(super) foreground: Pixel,
     pub(super) background: Pixel,
@@ -18,6 +27,12 @@ impl CharColors {
         background: Pixel::BLACK,
     };
 
+    const fn swap(&self) -> Self {
+        Self {
+            foreground: self.background,
+            background: self.foreground,
+        }
+    }
     const fn apply(&self, bit: CharBit) -> Pixel {
         match bit {
             CharBit::Foreground => self.foreground,
@@ -26,222 +41,682 @@ impl CharColors {
     }
 }
 
+/// A row and a column of the char buffer
 #[derive(Clone, Copy, Debug)]
-struct CharPosition {
-    frame_resolution: Resolution,
-    /// Pixel position of the upper right corner of the character
-    pixel_position: PixelPosition,
+pub(super) struct CharPosition {
+    pub(super) row: usize,
+    pub(super) column: usize,
 }
 
 impl CharPosition {
-    fn initial(frame_resolution: Resolution) -> Self {
-        const INITIAL_POSITION: PixelPosition = PixelPosition {
-            horizontal: 0,
-            vertical: 0,
-        };
-        Self {
-            frame_resolution,
-            pixel_position: INITIAL_POSITION,
-        }
-    }
-
-    fn the_whole_char_can_be_drawn_vertically(&self) -> bool {
-        self.pixel_position.vertical + CHAR_RESOLUTION.vertical < self.frame_resolution.vertical
-    }
+    pub(super) const INITIAL: Self = Self { row: 0, column: 0 };
 
-    fn go_to_unchecked(&mut self, row_index: usize, column_index: usize) {
-        self.pixel_position = PixelPosition {
-            horizontal: column_index * CHAR_RESOLUTION.horizontal,
-            vertical: row_index * CHAR_RESOLUTION.vertical,
+    /// Pixel position of the upper left corner of the character
+    const fn get_pixel_position(&self, cell_resolution: Resolution) -> PixelPosition {
+        PixelPosition {
+            horizontal: self.column * cell_resolution.horizontal,
+            vertical: self.row * cell_resolution.vertical,
         }
     }
+}
 
-    fn go_right_or_start_new_line(&mut self) {
-        self.pixel_position.horizontal += CHAR_RESOLUTION.horizontal;
-        if self.pixel_position.horizontal >= self.frame_resolution.horizontal {
-            self.go_down();
-            self.go_back_to_start_of_line();
-        }
-    }
-    fn go_down(&mut self) {
-        self.pixel_position.vertical += CHAR_RESOLUTION.vertical;
-    }
-    fn go_back_to_start_of_line(&mut self) {
-        self.pixel_position.horizontal = 0;
-    }
+/// Which part of a line or of the screen is erased, relatively to the cursor
+#[derive(Clone, Copy, Debug)]
+pub(super) enum EraseMode {
+    ToEnd,
+    /// Including the cursor
+    ToStart,
+    All,
 }
 
-/// A printable char is an ASCII char between U+0020 and U+007E
+/// A printable char is any char but the control chars, drawn with the replacement glyph if missing from the font
 ///
 /// The space char is printable, as it has a background color, but it is still not visible
 #[derive(Clone, Copy, Debug)]
-pub(super) struct PrintableChar(u8);
+pub(super) struct PrintableChar(char);
 
 impl PrintableChar {
-    const FIRST_PRINTABLE_CHAR: u8 = 0x20;
-    const LAST_PRINTABLE_CHAR: u8 = 0x7E;
-
-    const NOT_PRINTABLE_CHAR: u8 = 0;
+    const NOT_PRINTABLE_CHAR: char = '\0';
     const NONE: Self = Self(Self::NOT_PRINTABLE_CHAR);
     const fn is_none(&self) -> bool {
         self.0 == Self::NOT_PRINTABLE_CHAR
     }
 
-    pub(super) const SPACE: Self = Self(0x20);
+    pub(super) const SPACE: Self = Self(' ');
 
-    pub(super) const fn get_index_from_first_printable_char(&self) -> usize {
-        (self.0 - 0x20) as usize
+    pub(super) const fn get_char(&self) -> char {
+        self.0
     }
 }
 
-impl TryFrom<u8> for PrintableChar {
+impl TryFrom<char> for PrintableChar {
     type Error = ();
-    /// Succeeds only if `byte` is an ASCII printable char (between U+0020 and U+007E)
-    fn try_from(byte: u8) -> Result<Self, Self::Error> {
-        match byte {
-            Self::FIRST_PRINTABLE_CHAR..=Self::LAST_PRINTABLE_CHAR => Ok(PrintableChar(byte)),
-            _ => Err(()),
+    /// Succeeds only if `c` isn't a control char (U+0000 to U+001F, and U+007F to U+009F)
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if c.is_control() {
+            Err(())
+        } else {
+            Ok(PrintableChar(c))
         }
     }
 }
 
+/// One cell of the screen, none if nothing was ever drawn there
 #[derive(Clone, Copy, Debug)]
-struct OptionalColoredChar {
+pub(super) struct OptionalColoredChar {
     printable_char: PrintableChar,
     colors: CharColors,
+    is_underlined: bool,
 }
 
 impl OptionalColoredChar {
     const NONE: Self = Self {
         printable_char: PrintableChar::NONE,
         colors: CharColors::NONE,
+        is_underlined: false,
     };
-    const fn is_none(&self) -> bool {
+    pub(super) const fn is_none(&self) -> bool {
         self.printable_char.is_none()
     }
+    /// Whether it looks like a cell where nothing was drawn, like the erased ones
+    pub(super) fn is_blank(&self) -> bool {
+        self.is_none()
+            || (self.printable_char.get_char() == ' '
+                && self.colors == CharColors::DEFAULT
+                && !self.is_underlined)
+    }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub(super) struct CharBuffer {
-    frame_buffer: FrameBuffer,
+    /// Only the terminal shown has the frame buffer, the others only keeping their cells up to date
+    frame_buffer: Option<FrameBuffer>,
+    screen_resolution: Resolution,
+    font: Font,
+    font_scale: FontScale,
+    /// Computed from `font_scale`, for the current font and screen
+    scale_factor: usize,
     char_colors: CharColors,
+    is_underlined: bool,
     cursor_position: CharPosition,
+    cursor_shape: CursorShape,
+    /// Hidden and shown with the DECTCEM private mode
+    is_cursor_enabled: bool,
+    /// Whether the cursor is on screen right now, which changes as it blinks
+    is_cursor_drawn: bool,
     width: usize,
     height: usize,
+    /// The first and last rows scrolled when the cursor goes past them, the whole screen by default
+    scroll_region: (usize, usize),
+    /// The chars on screen, row after row, so that they can be drawn again
+    ///
+    /// Empty until `allocate_cells`, as it needs the heap
+    cells: Vec<OptionalColoredChar>,
+    scrollback: Scrollback,
 }
 
 impl CharBuffer {
-    const MAX_WIDTH: usize = Resolution::MAX_SUPPORTED.horizontal / CHAR_RESOLUTION.horizontal;
-    const MAX_HEIGHT: usize = Resolution::MAX_SUPPORTED.vertical / CHAR_RESOLUTION.vertical;
-
-    pub(super) fn new(frame_buffer: FrameBuffer) -> Self {
-        let width = frame_buffer.resolution.horizontal / CHAR_RESOLUTION.horizontal;
-        let height = frame_buffer.resolution.vertical / CHAR_RESOLUTION.vertical;
-        Self {
-            frame_buffer,
+    /// Uses the built-in font, automatically scaled, and is hidden until given the frame buffer
+    pub(super) fn new(screen_resolution: Resolution) -> Self {
+        let mut char_buffer = Self {
+            frame_buffer: None,
+            screen_resolution,
+            font: Font::BuiltIn,
+            font_scale: FontScale::Auto,
+            scale_factor: 1,
             char_colors: CharColors::DEFAULT,
-            cursor_position: CharPosition::initial(frame_buffer.resolution),
-            width,
-            height,
-        }
+            is_underlined: false,
+            cursor_position: CharPosition::INITIAL,
+            cursor_shape: CursorShape::Underline,
+            is_cursor_enabled: true,
+            is_cursor_drawn: false,
+            width: 0,
+            height: 0,
+            scroll_region: (0, 0),
+            cells: Vec::new(),
+            scrollback: Scrollback::new(),
+        };
+        char_buffer.fit_font();
+        char_buffer
+    }
+
+    pub(super) const fn frame_buffer(&self) -> Option<&FrameBuffer> {
+        self.frame_buffer.as_ref()
+    }
+    pub(super) fn frame_buffer_mut(&mut self) -> Option<&mut FrameBuffer> {
+        self.frame_buffer.as_mut()
+    }
+    /// Draws the cells on the frame buffer, from now on, but not the cursor yet
+    pub(super) fn show(&mut self, frame_buffer: FrameBuffer) {
+        self.frame_buffer = Some(frame_buffer);
+        self.redraw();
+    }
+    /// Gives the frame buffer back, if shown
+    pub(super) fn hide(&mut self) -> Option<FrameBuffer> {
+        self.is_cursor_drawn = false;
+        self.frame_buffer.take()
+    }
+    pub(super) const fn width(&self) -> usize {
+        self.width
+    }
+    pub(super) const fn height(&self) -> usize {
+        self.height
     }
 
     pub(super) fn set_char_colors(&mut self, char_colors: CharColors) {
         self.char_colors = char_colors;
     }
+    pub(super) fn set_underlined(&mut self, is_underlined: bool) {
+        self.is_underlined = is_underlined;
+    }
+    /// The text is kept, as much as fits the new rows and columns
+    pub(super) fn set_font(&mut self, font: Font) {
+        self.font = font;
+        self.refit_font();
+    }
+    /// The text is kept, as much as fits the new rows and columns
+    pub(super) fn set_font_scale(&mut self, font_scale: FontScale) {
+        self.font_scale = font_scale;
+        self.refit_font();
+    }
+
+    /// Keeps the chars on screen from now on, which the cursor and `redraw` need
+    pub(super) fn allocate_cells(&mut self) -> Result<(), TryReserveError> {
+        let cell_count = self.width * self.height;
+        self.cells.try_reserve_exact(cell_count)?;
+        self.cells.resize(cell_count, OptionalColoredChar::NONE);
+        Ok(())
+    }
+    /// Keeps the lines scrolled off the top of the screen from now on, if the cells are allocated, in up to
+    /// `max_size` bytes
+    pub(super) fn enable_scrollback(&mut self, max_size: usize) {
+        self.scrollback.enable(max_size);
+    }
+    /// Shows the lines `view_offset` lines back, clamped to the lines kept
+    pub(super) fn set_view_offset(&mut self, view_offset: usize) {
+        if self.scrollback.set_view_offset(view_offset) {
+            self.redraw();
+        }
+    }
+    pub(super) const fn view_offset(&self) -> usize {
+        self.scrollback.view_offset()
+    }
 
     pub(super) fn put_char(&mut self, printable_char: PrintableChar) {
-        self.draw_printable_char(printable_char);
-        self.cursor_position.go_right_or_start_new_line();
-        self.scroll_if_needed();
+        let cell = OptionalColoredChar {
+            printable_char,
+            colors: self.char_colors,
+            is_underlined: self.is_underlined,
+        };
+        self.set_cell(self.cursor_position, cell);
+        self.draw_cell(self.cursor_position, cell);
+        self.cursor_position.column += 1;
+        if self.cursor_position.column >= self.width {
+            self.go_to_line_start();
+            self.go_down();
+        }
+    }
+
+    pub(super) const fn cursor_position(&self) -> CharPosition {
+        self.cursor_position
+    }
+    pub(super) fn set_cursor_shape(&mut self, cursor_shape: CursorShape) {
+        self.hide_cursor();
+        self.cursor_shape = cursor_shape;
+    }
+    pub(super) fn set_cursor_enabled(&mut self, is_cursor_enabled: bool) {
+        self.hide_cursor();
+        self.is_cursor_enabled = is_cursor_enabled;
+    }
+    /// Draws the cursor over its cell, unless it's disabled or scrolled back from
+    ///
+    /// Without cells, what's under the cursor couldn't be drawn again, so it isn't drawn either
+    pub(super) fn show_cursor(&mut self) {
+        if self.is_cursor_drawn
+            || !self.is_cursor_enabled
+            || self.scrollback.view_offset() != 0
+            || self.cells.is_empty()
+        {
+            return;
+        }
+        self.draw_cursor();
+        self.is_cursor_drawn = true;
+    }
+    /// Draws the cell under the cursor again, which must be done before anything else is drawn
+    pub(super) fn hide_cursor(&mut self) {
+        if self.is_cursor_drawn {
+            self.draw_cell(self.cursor_position, self.get_cell(self.cursor_position));
+            self.is_cursor_drawn = false;
+        }
+    }
+    pub(super) fn blink_cursor(&mut self) {
+        if self.is_cursor_drawn {
+            self.hide_cursor();
+        } else {
+            self.show_cursor();
+        }
+    }
+    /// Clamped to the screen
+    pub(super) fn go_to(&mut self, position: CharPosition) {
+        self.cursor_position = CharPosition {
+            row: position.row.min(self.height - 1),
+            column: position.column.min(self.width - 1),
+        };
+    }
+    /// Stops at the top of the scroll region, or of the screen if the cursor was above the region
+    pub(super) fn go_up_by(&mut self, count: usize) {
+        let top = self.get_scroll_region_top_from_cursor();
+        self.cursor_position.row = self.cursor_position.row.saturating_sub(count).max(top);
+    }
+    /// Stops at the bottom of the scroll region, or of the screen if the cursor was below the region
+    pub(super) fn go_down_by(&mut self, count: usize) {
+        let bottom = self.get_scroll_region_bottom_from_cursor();
+        self.cursor_position.row = (self.cursor_position.row + count).min(bottom);
+    }
+    pub(super) fn go_left_by(&mut self, count: usize) {
+        self.cursor_position.column = self.cursor_position.column.saturating_sub(count);
+    }
+    pub(super) fn go_right_by(&mut self, count: usize) {
+        self.cursor_position.column = (self.cursor_position.column + count).min(self.width - 1);
     }
 
+    /// Scrolls the scroll region up if the cursor is on its last row
     pub(super) fn go_down(&mut self) {
-        self.cursor_position.go_down();
-        self.scroll_if_needed();
+        if self.cursor_position.row == self.scroll_region.1 {
+            self.scroll_up(1);
+        } else if self.cursor_position.row + 1 < self.height {
+            self.cursor_position.row += 1;
+        }
+    }
+    /// Scrolls the scroll region down if the cursor is on its first row
+    pub(super) fn go_up(&mut self) {
+        if self.cursor_position.row == self.scroll_region.0 {
+            self.scroll_down(1);
+        } else if self.cursor_position.row > 0 {
+            self.cursor_position.row -= 1;
+        }
     }
     pub(super) fn go_to_line_start(&mut self) {
-        self.cursor_position.go_back_to_start_of_line();
-        self.scroll_if_needed();
-    }
-
-    fn draw_printable_char(&mut self, printable_char: PrintableChar) {
-        let mut pixel_position = self.cursor_position.pixel_position;
-        for bit_line in CharBitMap::from(printable_char).get_lines() {
-            for bit in bit_line.get_bits() {
-                let pixel = self.char_colors.apply(bit);
-                self.frame_buffer
-                    .draw_pixel_if_visible(pixel_position, pixel);
-                pixel_position.horizontal += 1;
-            }
-            pixel_position.horizontal = self.cursor_position.pixel_position.horizontal;
-            pixel_position.vertical += 1;
+        self.cursor_position.column = 0;
+    }
+
+    /// Rows are counted from 0, and the whole screen is used again if the region is empty or too large
+    ///
+    /// Like on a VT100, the cursor goes back home
+    pub(super) fn set_scroll_region(&mut self, top: usize, bottom: usize) {
+        self.scroll_region = if top < bottom && bottom < self.height {
+            (top, bottom)
+        } else {
+            (0, self.height - 1)
+        };
+        self.cursor_position = CharPosition::INITIAL;
+    }
+    /// Moves the rows of the scroll region up, clearing the rows appearing at its bottom
+    pub(super) fn scroll_up(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        if top == 0 {
+            self.push_to_scrollback(0..count.min(bottom + 1));
+        }
+        self.move_rows_up(top..bottom + 1, count);
+    }
+    /// Moves the rows of the scroll region down, clearing the rows appearing at its top
+    pub(super) fn scroll_down(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        self.move_rows_down(top..bottom + 1, count);
+    }
+    /// Moves the rows from the cursor's one down, within the scroll region, and goes to the line start
+    pub(super) fn insert_lines(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        let row = self.cursor_position.row;
+        if row < top || row > bottom {
+            return;
         }
+        self.move_rows_down(row..bottom + 1, count);
+        self.go_to_line_start();
     }
-    fn draw_char_if_some(&mut self, optional_char: OptionalColoredChar) {
-        if optional_char.is_none() {
+    /// Moves the rows below the cursor's one up, within the scroll region, and goes to the line start
+    pub(super) fn delete_lines(&mut self, count: usize) {
+        let (top, bottom) = self.scroll_region;
+        let row = self.cursor_position.row;
+        if row < top || row > bottom {
             return;
         }
-        self.draw_printable_char(optional_char.printable_char);
+        self.move_rows_up(row..bottom + 1, count);
+        self.go_to_line_start();
     }
 
-    fn get_pixel_position_moving_cursor(
-        &mut self,
-        row_index: usize,
-        column_index: usize,
-    ) -> PixelPosition {
-        self.cursor_position
-            .go_to_unchecked(row_index, column_index);
-        self.cursor_position.pixel_position
-    }
-    fn copy_char(&mut self, dest: PixelPosition, src: PixelPosition) {
-        for h_pixel_index in 0..CHAR_RESOLUTION.horizontal {
-            for v_pixel_index in 0..CHAR_RESOLUTION.vertical {
-                let src = PixelPosition {
-                    horizontal: src.horizontal + h_pixel_index,
-                    vertical: src.vertical + v_pixel_index,
-                };
-                let dest = PixelPosition {
-                    horizontal: dest.horizontal + h_pixel_index,
-                    vertical: dest.vertical + v_pixel_index,
-                };
-                self.frame_buffer.copy_one_pixel(dest, src);
-            }
+    /// Moves the chars from the cursor right, the ones pushed past the end of the line being lost
+    pub(super) fn insert_chars(&mut self, count: usize) {
+        let CharPosition { row, column } = self.cursor_position;
+        let count = count.min(self.width - column);
+        self.move_chars(row, column..self.width - count, column + count);
+        self.clear_chars(row, column..column + count);
+    }
+    /// Moves the chars after the cursor left over it, blank chars appearing at the end of the line
+    pub(super) fn delete_chars(&mut self, count: usize) {
+        let CharPosition { row, column } = self.cursor_position;
+        let count = count.min(self.width - column);
+        self.move_chars(row, column + count..self.width, column);
+        self.clear_chars(row, self.width - count..self.width);
+    }
+    /// Erases chars from the cursor, leaving the next ones in place
+    pub(super) fn erase_chars(&mut self, count: usize) {
+        let CharPosition { row, column } = self.cursor_position;
+        self.clear_chars(row, column..(column + count).min(self.width));
+    }
+
+    /// Erased chars get the current background color
+    pub(super) fn erase_in_line(&mut self, erase_mode: EraseMode) {
+        let CharPosition { row, column } = self.cursor_position;
+        let columns = match erase_mode {
+            EraseMode::ToEnd => column..self.width,
+            EraseMode::ToStart => 0..column + 1,
+            EraseMode::All => 0..self.width,
+        };
+        self.clear_chars(row, columns);
+    }
+    /// Erased chars get the current background color
+    pub(super) fn erase_in_display(&mut self, erase_mode: EraseMode) {
+        let row = self.cursor_position.row;
+        self.erase_in_line(erase_mode);
+        let rows = match erase_mode {
+            EraseMode::ToEnd => row + 1..self.height,
+            EraseMode::ToStart => 0..row,
+            EraseMode::All => 0..self.height,
+        };
+        for row in rows {
+            self.clear_chars(row, 0..self.width);
+        }
+    }
+
+    /// Sizes the buffer for the scaled glyphs of the font, which always fit the supported resolutions
+    fn fit_font(&mut self) {
+        self.scale_factor = self
+            .font_scale
+            .get_factor(self.font.glyph_resolution(), self.screen_resolution);
+        let cell_resolution = self.get_cell_resolution();
+        self.width = self.screen_resolution.horizontal / cell_resolution.horizontal;
+        self.height = self.screen_resolution.vertical / cell_resolution.vertical;
+        self.scroll_region = (0, self.height - 1);
+    }
+    /// The text is drawn again, cut to the new width, the rows above the cursor going to the scrollback
+    /// if there are too many for the new height
+    ///
+    /// Without cells, the screen is cleared and the cursor goes back home
+    fn refit_font(&mut self) {
+        let (previous_width, previous_height) = (self.width, self.height);
+        let previous_cells = mem::take(&mut self.cells);
+        self.fit_font();
+        self.scrollback.set_view_offset(0);
+        if previous_cells.is_empty() || self.allocate_cells().is_err() {
+            self.cursor_position = CharPosition::INITIAL;
+            self.erase_in_display(EraseMode::All);
+            return;
+        }
+        let removed_row_count = (self.cursor_position.row + 1).saturating_sub(self.height);
+        for row in 0..removed_row_count {
+            self.scrollback
+                .push_line(&previous_cells[row * previous_width..(row + 1) * previous_width]);
+        }
+        let kept_row_count = (previous_height - removed_row_count).min(self.height);
+        let kept_column_count = previous_width.min(self.width);
+        for row in 0..kept_row_count {
+            let previous_start = (removed_row_count + row) * previous_width;
+            let start = row * self.width;
+            self.cells[start..start + kept_column_count].copy_from_slice(
+                &previous_cells[previous_start..previous_start + kept_column_count],
+            );
+        }
+        self.cursor_position.row -= removed_row_count;
+        self.cursor_position.column = self.cursor_position.column.min(self.width - 1);
+        // The pixels past the last row and column aren't drawn by `redraw`
+        if let Some(frame_buffer) = &mut self.frame_buffer {
+            frame_buffer.fill(CharColors::NONE.background);
+        }
+        self.redraw();
+    }
+    /// The size of a char on screen, once scaled
+    fn get_cell_resolution(&self) -> Resolution {
+        let glyph_resolution = self.font.glyph_resolution();
+        Resolution {
+            horizontal: glyph_resolution.horizontal * self.scale_factor,
+            vertical: glyph_resolution.vertical * self.scale_factor,
+        }
+    }
+
+    fn get_scroll_region_top_from_cursor(&self) -> usize {
+        if self.cursor_position.row >= self.scroll_region.0 {
+            self.scroll_region.0
+        } else {
+            0
         }
     }
-    fn clear_char(&mut self, pixel_position: PixelPosition) {
-        for h_pixel_index in 0..CHAR_RESOLUTION.horizontal {
-            for v_pixel_index in 0..CHAR_RESOLUTION.vertical {
-                let pixel_position = PixelPosition {
-                    horizontal: pixel_position.horizontal + h_pixel_index,
-                    vertical: pixel_position.vertical + v_pixel_index,
+    fn get_scroll_region_bottom_from_cursor(&self) -> usize {
+        if self.cursor_position.row <= self.scroll_region.1 {
+            self.scroll_region.1
+        } else {
+            self.height - 1
+        }
+    }
+
+    /// Draws all the cells again, from the scrolled back lines shown, if any, but not the cursor
+    pub(super) fn redraw(&mut self) {
+        self.is_cursor_drawn = false;
+        let view_offset = self.scrollback.view_offset();
+        for row in 0..self.height {
+            for column in 0..self.width {
+                let cell = if row < view_offset {
+                    self.scrollback
+                        .get_viewed_line(row)
+                        .and_then(|line| line.get(column).copied())
+                } else {
+                    self.cells
+                        .get((row - view_offset) * self.width + column)
+                        .copied()
                 };
-                self.frame_buffer
-                    .draw_pixel_if_visible(pixel_position, Pixel::BLACK);
+                let position = CharPosition { row, column };
+                self.draw_cell(position, cell.unwrap_or(OptionalColoredChar::NONE));
             }
         }
     }
-    fn scroll_if_needed(&mut self) {
-        if self
-            .cursor_position
-            .the_whole_char_can_be_drawn_vertically()
+    fn get_cell(&self, position: CharPosition) -> OptionalColoredChar {
+        self.cells
+            .get(position.row * self.width + position.column)
+            .copied()
+            .unwrap_or(OptionalColoredChar::NONE)
+    }
+    fn set_cell(&mut self, position: CharPosition, cell: OptionalColoredChar) {
+        if let Some(screen_cell) = self
+            .cells
+            .get_mut(position.row * self.width + position.column)
         {
+            *screen_cell = cell;
+        }
+    }
+    fn get_rows_cells_mut(&mut self, rows: Range<usize>) -> Option<&mut [OptionalColoredChar]> {
+        self.cells
+            .get_mut(rows.start * self.width..rows.end * self.width)
+    }
+    fn push_to_scrollback(&mut self, rows: Range<usize>) {
+        for row in rows {
+            if let Some(row_cells) = self.cells.get(row * self.width..(row + 1) * self.width) {
+                self.scrollback.push_line(row_cells);
+            }
+        }
+    }
+    /// Clears the rows appearing at the bottom
+    fn move_rows_up(&mut self, rows: Range<usize>, count: usize) {
+        let count = count.min(rows.len());
+        let width = self.width;
+        if let Some(rows_cells) = self.get_rows_cells_mut(rows.clone()) {
+            rows_cells.rotate_left(count * width);
+        }
+        self.copy_rows(rows.start, rows.start + count..rows.end);
+        for row in rows.end - count..rows.end {
+            self.clear_chars(row, 0..self.width);
+        }
+    }
+    /// Clears the rows appearing at the top
+    fn move_rows_down(&mut self, rows: Range<usize>, count: usize) {
+        let count = count.min(rows.len());
+        let width = self.width;
+        if let Some(rows_cells) = self.get_rows_cells_mut(rows.clone()) {
+            rows_cells.rotate_right(count * width);
+        }
+        self.copy_rows(rows.start + count, rows.start..rows.end - count);
+        for row in rows.start..rows.start + count {
+            self.clear_chars(row, 0..self.width);
+        }
+    }
+    fn move_chars(&mut self, row: usize, src_columns: Range<usize>, dest_column: usize) {
+        if let Some(row_cells) = self.get_rows_cells_mut(row..row + 1) {
+            row_cells.copy_within(src_columns.clone(), dest_column);
+        }
+        let cell_resolution = self.get_cell_resolution();
+        let src = PixelRectangle {
+            position: CharPosition {
+                row,
+                column: src_columns.start,
+            }
+            .get_pixel_position(cell_resolution),
+            resolution: Resolution {
+                horizontal: src_columns.len() * cell_resolution.horizontal,
+                vertical: cell_resolution.vertical,
+            },
+        };
+        let dest = CharPosition {
+            row,
+            column: dest_column,
+        }
+        .get_pixel_position(cell_resolution);
+        if let Some(frame_buffer) = &mut self.frame_buffer {
+            frame_buffer.copy_rectangle(dest, src);
+        }
+    }
+    /// Cells which are none are drawn black, like the screen before anything is drawn
+    fn draw_cell(&mut self, position: CharPosition, cell: OptionalColoredChar) {
+        if self.frame_buffer.is_none() {
             return;
         }
-        for row_index in 1..self.height {
-            // starting with the second row !
-            for column_index in 0..self.width {
-                let src = self.get_pixel_position_moving_cursor(row_index, column_index);
-                let dest = self.get_pixel_position_moving_cursor(row_index - 1, column_index);
-                self.copy_char(dest, src);
+        let cell_resolution = self.get_cell_resolution();
+        let char_pixel_position = position.get_pixel_position(cell_resolution);
+        if cell.is_none() {
+            let rectangle = PixelRectangle {
+                position: char_pixel_position,
+                resolution: cell_resolution,
+            };
+            self.fill_rectangle(rectangle, CharColors::NONE.background);
+            return;
+        }
+        let glyph = self.font.get_glyph(cell.printable_char.get_char());
+        let glyph_resolution = self.font.glyph_resolution();
+        // Drawn with the foreground color when underlined
+        let underline_index = glyph_resolution.vertical.saturating_sub(2);
+        for v_bit_index in 0..glyph_resolution.vertical {
+            let is_underline = cell.is_underlined && v_bit_index == underline_index;
+            for h_bit_index in 0..glyph_resolution.horizontal {
+                let pixel = if is_underline {
+                    cell.colors.foreground
+                } else {
+                    cell.colors.apply(glyph.get_bit(h_bit_index, v_bit_index))
+                };
+                let block_position = PixelPosition {
+                    horizontal: char_pixel_position.horizontal + h_bit_index * self.scale_factor,
+                    vertical: char_pixel_position.vertical + v_bit_index * self.scale_factor,
+                };
+                self.draw_block(block_position, pixel);
+            }
+        }
+    }
+    /// The underline and the bar are one bit thick for 8 bits of the glyph size
+    fn draw_cursor(&mut self) {
+        let cell = self.get_cell(self.cursor_position);
+        let colors = if cell.is_none() {
+            self.char_colors
+        } else {
+            cell.colors
+        };
+        let cell_resolution = self.get_cell_resolution();
+        let glyph_resolution = self.font.glyph_resolution();
+        let mut cursor = PixelRectangle {
+            position: self.cursor_position.get_pixel_position(cell_resolution),
+            resolution: cell_resolution,
+        };
+        match self.cursor_shape {
+            CursorShape::Block => {
+                let block_cell = OptionalColoredChar {
+                    printable_char: if cell.is_none() {
+                        PrintableChar::SPACE
+                    } else {
+                        cell.printable_char
+                    },
+                    colors: colors.swap(),
+                    is_underlined: cell.is_underlined,
+                };
+                self.draw_cell(self.cursor_position, block_cell);
+                return;
             }
+            CursorShape::Underline => {
+                let thickness = (glyph_resolution.vertical / 8).max(1) * self.scale_factor;
+                cursor.position.vertical += cell_resolution.vertical - thickness;
+                cursor.resolution.vertical = thickness;
+            }
+            CursorShape::Bar => {
+                cursor.resolution.horizontal =
+                    (glyph_resolution.horizontal / 8).max(1) * self.scale_factor;
+            }
+        }
+        self.fill_rectangle(cursor, colors.foreground);
+    }
+    /// One bit of a glyph, as a square of the scale factor
+    fn draw_block(&mut self, block_position: PixelPosition, pixel: Pixel) {
+        let block = PixelRectangle {
+            position: block_position,
+            resolution: Resolution {
+                horizontal: self.scale_factor,
+                vertical: self.scale_factor,
+            },
+        };
+        self.fill_rectangle(block, pixel);
+    }
+    /// Nothing is drawn while hidden
+    fn fill_rectangle(&mut self, rectangle: PixelRectangle, pixel: Pixel) {
+        if let Some(frame_buffer) = &mut self.frame_buffer {
+            frame_buffer.fill_rectangle(rectangle, pixel);
         }
-        // clear last row
-        for column_index in 0..self.width {
-            let last_row_index = self.height - 1;
-            let pixel_position =
-                self.get_pixel_position_moving_cursor(last_row_index, column_index);
-            self.clear_char(pixel_position);
+    }
+
+    /// Moves the pixel lines of the rows at once
+    fn copy_rows(&mut self, dest_row: usize, src_rows: Range<usize>) {
+        let cell_height = self.get_cell_resolution().vertical;
+        if let Some(frame_buffer) = &mut self.frame_buffer {
+            frame_buffer.copy_lines(
+                dest_row * cell_height,
+                src_rows.start * cell_height..src_rows.end * cell_height,
+            );
         }
     }
+    fn clear_chars(&mut self, row: usize, columns: Range<usize>) {
+        let cleared_cell = OptionalColoredChar {
+            printable_char: PrintableChar::SPACE,
+            colors: self.char_colors,
+            is_underlined: false,
+        };
+        for column in columns.clone() {
+            self.set_cell(CharPosition { row, column }, cleared_cell);
+        }
+        let cell_resolution = self.get_cell_resolution();
+        let chars = PixelRectangle {
+            position: CharPosition {
+                row,
+                column: columns.start,
+            }
+            .get_pixel_position(cell_resolution),
+            resolution: Resolution {
+                horizontal: columns.len() * cell_resolution.horizontal,
+                vertical: cell_resolution.vertical,
+            },
+        };
+        self.fill_rectangle(chars, self.char_colors.background);
+    }
 }
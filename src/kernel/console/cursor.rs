@@ -0,0 +1,9 @@
+/// How the text cursor is drawn over the char it's on
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum CursorShape {
+    /// The char is drawn with its colors swapped
+    Block,
+    /// Like on the VGA text mode
+    Underline,
+    Bar,
+}
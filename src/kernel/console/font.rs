@@ -0,0 +1,89 @@
+use crate::kernel::console::char_bitmaps;
+use crate::kernel::console::char_bitmaps::{CharBit, CHAR_RESOLUTION, REPLACEMENT_CHAR};
+use crate::kernel::console::psf::PsfFont;
+use crate::kernel::native_graphics::Resolution;
+
+/// Drawn for the chars missing from a font without the replacement glyph
+const FALLBACK_CHAR: char = '?';
+
+/// Where the console gets its glyphs from
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Font {
+    /// The 8x16 glyphs compiled into the kernel, available from the very start
+    BuiltIn,
+    Psf(PsfFont),
+}
+
+impl Font {
+    pub(super) const fn glyph_resolution(&self) -> Resolution {
+        match self {
+            Self::BuiltIn => CHAR_RESOLUTION,
+            Self::Psf(psf_font) => psf_font.glyph_resolution(),
+        }
+    }
+
+    /// Chars missing from the font get the replacement glyph, or `?`, or the first glyph of the font
+    pub(super) fn get_glyph(&self, c: char) -> Glyph {
+        let bitmap: &'static [u8] = match self {
+            Self::BuiltIn => char_bitmaps::find_bitmap(c)
+                .or_else(|| char_bitmaps::find_bitmap(REPLACEMENT_CHAR))
+                .expect("the built-in font must have the replacement glyph"),
+            Self::Psf(psf_font) => {
+                let glyph_index = psf_font
+                    .find_glyph_index(c)
+                    .or_else(|| psf_font.find_glyph_index(REPLACEMENT_CHAR))
+                    .or_else(|| psf_font.find_glyph_index(FALLBACK_CHAR))
+                    .unwrap_or(0);
+                psf_font.get_bitmap(glyph_index)
+            }
+        };
+        Glyph {
+            bitmap,
+            resolution: self.glyph_resolution(),
+        }
+    }
+}
+
+/// How much the glyphs are enlarged, each bit of a glyph being drawn as an NxN block of pixels
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum FontScale {
+    /// The largest scale still leaving 80 columns and 25 rows, like the VGA text mode
+    Auto,
+    /// Lowered if the glyphs wouldn't fit the screen
+    Fixed(usize),
+}
+
+impl FontScale {
+    const MIN_AUTO_SIZE: Resolution = Resolution {
+        horizontal: 80,
+        vertical: 25,
+    };
+
+    /// At least 1, and never so large that a single char wouldn't fit the screen
+    pub(super) fn get_factor(&self, glyph_resolution: Resolution, screen: Resolution) -> usize {
+        let max_factor = (screen.horizontal / glyph_resolution.horizontal)
+            .min(screen.vertical / glyph_resolution.vertical);
+        let factor = match *self {
+            Self::Auto => (screen.horizontal
+                / (glyph_resolution.horizontal * Self::MIN_AUTO_SIZE.horizontal))
+                .min(screen.vertical / (glyph_resolution.vertical * Self::MIN_AUTO_SIZE.vertical)),
+            Self::Fixed(factor) => factor.min(max_factor),
+        };
+        factor.max(1)
+    }
+}
+
+/// The bitmap of one char, line by line, each line padded to whole bytes with its leftmost pixel as the highest bit
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Glyph {
+    bitmap: &'static [u8],
+    resolution: Resolution,
+}
+
+impl Glyph {
+    pub(super) fn get_bit(&self, horizontal: usize, vertical: usize) -> CharBit {
+        let bytes_per_line = self.resolution.horizontal.div_ceil(8);
+        let byte = self.bitmap[vertical * bytes_per_line + horizontal / 8];
+        CharBit::from(byte & (0x80 >> (horizontal % 8)) != 0)
+    }
+}
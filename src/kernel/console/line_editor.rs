@@ -0,0 +1,568 @@
+use crate::kernel::console;
+use crate::kernel::console::kprint;
+use crate::kernel::keyboard::{CharDecoder, KeyCode, KeyEvent, Layout};
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::mem;
+use core::ops::Range;
+
+const MAX_HISTORY_LENGTH: usize = 100;
+/// Used if there is no console to ask
+const DEFAULT_WIDTH: usize = 80;
+const DEFAULT_HEIGHT: usize = 25;
+
+/// What the user of a line editor must handle
+#[derive(Debug)]
+pub(crate) enum LineEvent {
+    /// Enter was pressed, the cursor being at the start of the next row
+    Submitted(String),
+    /// Tab was pressed, the completion being up to the user, through `text_before_cursor`, `insert` and `print_above`
+    CompletionRequested,
+}
+
+/// The state of a Ctrl+R search, which shows the newest history line containing the query
+#[derive(Debug)]
+struct HistorySearch {
+    query: String,
+    /// The history line shown, if any
+    history_index: Option<usize>,
+    is_failed: bool,
+    /// Given back if the search is cancelled
+    original_line: Vec<char>,
+}
+
+/// Edits a line typed after a prompt on the output terminal, with the shortcuts of readline :
+/// - the arrows, Home and End, Ctrl+A, Ctrl+E, Ctrl+B and Ctrl+F move the cursor, and Ctrl+arrows, Alt+B and
+///   Alt+F move it by words
+/// - Backspace, Delete, Ctrl+H and Ctrl+D erase chars
+/// - Ctrl+K, Ctrl+U, Ctrl+W and Alt+D kill text, which Ctrl+Y yanks back
+/// - the up and down arrows, Ctrl+P and Ctrl+N browse the history, and Ctrl+R searches it
+/// - Ctrl+L clears the screen, and Ctrl+C drops the line
+///
+/// The line may span several rows, the cursor being moved with escape sequences from the position it's known to
+/// have, as the console wraps and scrolls the rows the same way. Once a line is taller than the terminal, its rows
+/// scrolled off the top are left as they are : the cursor stays on the visible rows, drawn again from the top one
+#[derive(Debug)]
+pub(crate) struct LineEditor {
+    char_decoder: CharDecoder,
+    /// May contain escape sequences, like colors
+    prompt: &'static str,
+    line: Vec<char>,
+    /// The index in `line` of the char under the cursor
+    cursor: usize,
+    /// The columns of the output terminal, read at the start of every line
+    width: usize,
+    /// The rows of the output terminal, read at the start of every line
+    height: usize,
+    /// The row where the prompt starts, negative once scrolled off the top of the screen
+    start_row: isize,
+    /// The column where the prompt starts
+    start_column: usize,
+    /// Another prompt while searching
+    shown_prompt: String,
+    /// The chars of the prompt shown
+    shown_prompt_width: usize,
+    /// Where the cursor is on screen, in chars from the start of the prompt
+    screen_cursor: usize,
+    /// The oldest line first
+    history: VecDeque<String>,
+    /// The history line shown, if any
+    history_index: Option<usize>,
+    /// The line being typed before browsing the history, shown again after the newest history line
+    draft_line: Vec<char>,
+    /// The text last killed
+    kill_buffer: Vec<char>,
+    search: Option<HistorySearch>,
+}
+
+impl LineEditor {
+    pub(crate) fn new(layout: &'static Layout, prompt: &'static str) -> Self {
+        Self {
+            char_decoder: CharDecoder::new(layout),
+            prompt,
+            line: Vec::new(),
+            cursor: 0,
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            start_row: 0,
+            start_column: 0,
+            shown_prompt: String::new(),
+            shown_prompt_width: 0,
+            screen_cursor: 0,
+            history: VecDeque::new(),
+            history_index: None,
+            draft_line: Vec::new(),
+            kill_buffer: Vec::new(),
+            search: None,
+        }
+    }
+
+    /// Prints the prompt, after which the next line is typed
+    pub(crate) fn start_line(&mut self) {
+        self.read_start_position();
+        self.line.clear();
+        self.cursor = 0;
+        self.history_index = None;
+        self.draft_line.clear();
+        self.search = None;
+        self.shown_prompt = String::from(self.prompt);
+        self.shown_prompt_width = get_visible_width(self.prompt);
+        self.screen_cursor = 0;
+        self.note_printed_until(self.shown_prompt_width);
+        kprint!("{}", self.prompt);
+    }
+
+    /// Only the pressed keys are used, the console shortcuts being handled first
+    pub(crate) fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<LineEvent> {
+        if !key_event.is_pressed {
+            return None;
+        }
+        if self.search.is_some() && self.handle_search_key_event(key_event) {
+            return None;
+        }
+        let modifiers = key_event.modifiers;
+        if modifiers.is_ctrl() {
+            self.handle_ctrl_shortcut(key_event.key_code);
+            return None;
+        }
+        if modifiers.is_alt() {
+            match self.char_decoder.get_shortcut_char(key_event.key_code) {
+                Some('b') => self.set_cursor(self.find_previous_word_start()),
+                Some('f') => self.set_cursor(self.find_next_word_end()),
+                Some('d') => self.kill(self.cursor..self.find_next_word_end()),
+                _ => {}
+            }
+            return None;
+        }
+        match key_event.key_code {
+            KeyCode::ArrowLeft => self.set_cursor(self.cursor.saturating_sub(1)),
+            KeyCode::ArrowRight => self.set_cursor((self.cursor + 1).min(self.line.len())),
+            KeyCode::Home => self.set_cursor(0),
+            KeyCode::End => self.set_cursor(self.line.len()),
+            KeyCode::Delete => self.delete(self.cursor..(self.cursor + 1).min(self.line.len())),
+            KeyCode::ArrowUp => self.show_previous_history_line(),
+            KeyCode::ArrowDown => self.show_next_history_line(),
+            _ => {}
+        }
+        for c in self.char_decoder.decode(key_event) {
+            match c {
+                '\n' => return Some(LineEvent::Submitted(self.submit())),
+                '\t' => return Some(LineEvent::CompletionRequested),
+                '\u{8}' => self.delete(self.cursor.saturating_sub(1)..self.cursor),
+                c if !c.is_control() => self.insert_chars(&[c]),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub(crate) fn text_before_cursor(&self) -> String {
+        self.line[..self.cursor].iter().collect()
+    }
+    /// Inserts `s` at the cursor, which goes after it
+    pub(crate) fn insert(&mut self, s: &str) {
+        let chars: Vec<char> = s.chars().collect();
+        self.insert_chars(&chars);
+    }
+    /// Prints `text` on the rows below the line, then the prompt and the line again, like a completion list
+    pub(crate) fn print_above(&mut self, text: &str) {
+        let mut output = String::new();
+        self.move_to(&mut output, self.shown_prompt_width + self.line.len());
+        let _ = writeln!(output, "\n{}", text);
+        kprint!("{}", output);
+        self.read_start_position();
+        self.screen_cursor = 0;
+        let mut output = String::new();
+        self.write_prompt_and_line(&mut output, self.prompt);
+        kprint!("{}", output);
+    }
+
+    fn handle_ctrl_shortcut(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::ArrowLeft => return self.set_cursor(self.find_previous_word_start()),
+            KeyCode::ArrowRight => return self.set_cursor(self.find_next_word_end()),
+            _ => {}
+        }
+        match self.char_decoder.get_shortcut_char(key_code) {
+            Some('a') => self.set_cursor(0),
+            Some('e') => self.set_cursor(self.line.len()),
+            Some('b') => self.set_cursor(self.cursor.saturating_sub(1)),
+            Some('f') => self.set_cursor((self.cursor + 1).min(self.line.len())),
+            Some('h') => self.delete(self.cursor.saturating_sub(1)..self.cursor),
+            Some('d') => self.delete(self.cursor..(self.cursor + 1).min(self.line.len())),
+            Some('k') => self.kill(self.cursor..self.line.len()),
+            Some('u') => self.kill(0..self.cursor),
+            Some('w') => self.kill(self.find_previous_word_start()..self.cursor),
+            Some('y') => self.insert_chars(&self.kill_buffer.clone()),
+            Some('p') => self.show_previous_history_line(),
+            Some('n') => self.show_next_history_line(),
+            Some('r') => self.start_search(),
+            Some('l') => self.clear_screen(),
+            Some('c') => self.drop_line(),
+            _ => {}
+        }
+    }
+
+    /// Returns whether the key event was used, the keys ending the search being handled as usual afterwards
+    fn handle_search_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let modifiers = key_event.modifiers;
+        let shortcut_char = self.char_decoder.get_shortcut_char(key_event.key_code);
+        if modifiers.is_ctrl() {
+            match shortcut_char {
+                Some('r') => {
+                    let before = self
+                        .search
+                        .as_ref()
+                        .and_then(|search| search.history_index)
+                        .unwrap_or(self.history.len());
+                    self.search_history(before);
+                    return true;
+                }
+                Some('g') => {
+                    self.cancel_search();
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        if modifiers.is_ctrl() || modifiers.is_alt() {
+            self.end_search();
+            return false;
+        }
+        match key_event.key_code {
+            KeyCode::Escape => self.cancel_search(),
+            KeyCode::Backspace => {
+                if let Some(search) = &mut self.search {
+                    search.query.pop();
+                }
+                self.search_history(self.history.len());
+            }
+            KeyCode::Enter
+            | KeyCode::KeypadEnter
+            | KeyCode::Tab
+            | KeyCode::ArrowLeft
+            | KeyCode::ArrowRight
+            | KeyCode::ArrowUp
+            | KeyCode::ArrowDown
+            | KeyCode::Home
+            | KeyCode::End
+            | KeyCode::Delete => {
+                self.end_search();
+                return false;
+            }
+            _ => {
+                let mut query_chars = self
+                    .char_decoder
+                    .decode(key_event)
+                    .filter(|c| !c.is_control())
+                    .peekable();
+                if query_chars.peek().is_none() {
+                    return true;
+                }
+                if let Some(search) = &mut self.search {
+                    search.query.extend(query_chars);
+                }
+                let from = self
+                    .search
+                    .as_ref()
+                    .and_then(|search| search.history_index)
+                    .map_or(self.history.len(), |index| index + 1);
+                self.search_history(from);
+            }
+        }
+        true
+    }
+    fn start_search(&mut self) {
+        self.search = Some(HistorySearch {
+            query: String::new(),
+            history_index: None,
+            is_failed: false,
+            original_line: self.line.clone(),
+        });
+        self.redraw_search();
+    }
+    /// Shows the newest line before the history line `before` containing the query, or keeps the line shown
+    fn search_history(&mut self, before: usize) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        let found_line = self
+            .history
+            .iter()
+            .enumerate()
+            .take(before)
+            .rev()
+            .find_map(|(index, line)| line.find(search.query.as_str()).map(|at| (index, line, at)));
+        search.is_failed = found_line.is_none();
+        if let Some((index, line, at)) = found_line {
+            search.history_index = Some(index);
+            self.line = line.chars().collect();
+            self.cursor = line[..at].chars().count();
+        }
+        self.redraw_search();
+    }
+    /// Keeps the line found
+    fn end_search(&mut self) {
+        if self.search.take().is_some() {
+            self.history_index = None;
+            let mut output = String::new();
+            self.write_prompt_and_line(&mut output, self.prompt);
+            kprint!("{}", output);
+        }
+    }
+    /// Gives the line typed before the search back
+    fn cancel_search(&mut self) {
+        if let Some(search) = &mut self.search {
+            self.line = mem::take(&mut search.original_line);
+            self.cursor = self.line.len();
+        }
+        self.end_search();
+    }
+    fn redraw_search(&mut self) {
+        let Some(search) = &self.search else {
+            return;
+        };
+        let prompt = format!(
+            "({}reverse-i-search)`{}': ",
+            if search.is_failed { "failed " } else { "" },
+            search.query
+        );
+        let mut output = String::new();
+        self.write_prompt_and_line(&mut output, &prompt);
+        kprint!("{}", output);
+    }
+
+    /// Leaves the cursor after the line
+    fn submit(&mut self) -> String {
+        let mut output = String::new();
+        self.move_to(&mut output, self.shown_prompt_width + self.line.len());
+        output.push('\n');
+        kprint!("{}", output);
+        let line: String = self.line.iter().collect();
+        self.push_history_line(&line);
+        line
+    }
+    /// Starts a new line, like Ctrl+C in a shell
+    fn drop_line(&mut self) {
+        let mut output = String::new();
+        self.move_to(&mut output, self.shown_prompt_width + self.line.len());
+        output.push_str("^C\n");
+        kprint!("{}", output);
+        self.start_line();
+    }
+    fn clear_screen(&mut self) {
+        let mut output = String::from("\x1b[H\x1b[2J");
+        self.start_row = 0;
+        self.start_column = 0;
+        self.screen_cursor = 0;
+        self.write_prompt_and_line(&mut output, self.prompt);
+        kprint!("{}", output);
+    }
+
+    fn push_history_line(&mut self, line: &str) {
+        self.history_index = None;
+        if line.trim().is_empty() || self.history.back().is_some_and(|last| last == line) {
+            return;
+        }
+        if self.history.len() >= MAX_HISTORY_LENGTH {
+            self.history.pop_front();
+        }
+        self.history.push_back(String::from(line));
+    }
+    /// Stays on the oldest line
+    fn show_previous_history_line(&mut self) {
+        let index = match self.history_index {
+            None => {
+                self.draft_line = self.line.clone();
+                self.history.len().checked_sub(1)
+            }
+            Some(index) => Some(index.saturating_sub(1)),
+        };
+        if let Some(index) = index {
+            self.history_index = Some(index);
+            self.replace_line(self.history[index].chars().collect());
+        }
+    }
+    fn show_next_history_line(&mut self) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+        if index + 1 < self.history.len() {
+            self.history_index = Some(index + 1);
+            self.replace_line(self.history[index + 1].chars().collect());
+        } else {
+            self.history_index = None;
+            let draft_line = mem::take(&mut self.draft_line);
+            self.replace_line(draft_line);
+        }
+    }
+
+    /// The cursor goes to the end of the new line
+    fn replace_line(&mut self, line: Vec<char>) {
+        self.line = line;
+        self.cursor = self.line.len();
+        self.redraw_from(0);
+    }
+    fn insert_chars(&mut self, chars: &[char]) {
+        let index = self.cursor;
+        self.line.splice(index..index, chars.iter().copied());
+        self.cursor += chars.len();
+        self.redraw_from(index);
+    }
+    /// The cursor goes where the chars were
+    fn delete(&mut self, chars: Range<usize>) {
+        if chars.is_empty() {
+            return;
+        }
+        let start = chars.start;
+        self.line.drain(chars);
+        self.cursor = start;
+        self.redraw_from(start);
+    }
+    /// Deletes the chars, keeping them for Ctrl+Y
+    fn kill(&mut self, chars: Range<usize>) {
+        if !chars.is_empty() {
+            self.kill_buffer = self.line[chars.clone()].to_vec();
+            self.delete(chars);
+        }
+    }
+    fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor;
+        let mut output = String::new();
+        self.move_to(&mut output, self.shown_prompt_width + cursor);
+        kprint!("{}", output);
+    }
+
+    /// Words are separated by whitespace, like in a shell
+    fn find_previous_word_start(&self) -> usize {
+        let mut index = self.cursor;
+        while index > 0 && self.line[index - 1].is_whitespace() {
+            index -= 1;
+        }
+        while index > 0 && !self.line[index - 1].is_whitespace() {
+            index -= 1;
+        }
+        index
+    }
+    fn find_next_word_end(&self) -> usize {
+        let mut index = self.cursor;
+        while index < self.line.len() && self.line[index].is_whitespace() {
+            index += 1;
+        }
+        while index < self.line.len() && !self.line[index].is_whitespace() {
+            index += 1;
+        }
+        index
+    }
+
+    fn redraw_from(&mut self, index: usize) {
+        let mut output = String::new();
+        self.write_line_from(&mut output, index);
+        kprint!("{}", output);
+    }
+    /// Replaces the prompt shown by `prompt`, then draws the line
+    ///
+    /// The prompt is only printed if its start is still on screen, the line being laid out after it anyway
+    fn write_prompt_and_line(&mut self, output: &mut String, prompt: &str) {
+        let shown_prompt_width = get_visible_width(prompt);
+        if self.start_row >= 0 {
+            self.move_to(output, 0);
+            output.push_str(prompt);
+            self.note_printed_until(shown_prompt_width);
+        }
+        self.shown_prompt = String::from(prompt);
+        self.shown_prompt_width = shown_prompt_width;
+        self.write_line_from(output, 0);
+    }
+    /// Erases everything after the line, which may have been longer, then puts the cursor back
+    ///
+    /// Only the chars on the visible rows are printed, the prompt and the whole line being printed again from the
+    /// top row if the line got shorter than the rows scrolled off
+    fn write_line_from(&mut self, output: &mut String, mut index: usize) {
+        let first_visible_offset = self.get_first_visible_offset();
+        if self.shown_prompt_width + self.line.len() < first_visible_offset {
+            self.move_to(output, first_visible_offset);
+            (self.start_row, self.start_column) = (0, 0);
+            output.push_str(&self.shown_prompt);
+            self.note_printed_until(self.shown_prompt_width);
+            index = 0;
+        }
+        let start = (self.shown_prompt_width + index).max(self.get_first_visible_offset());
+        self.move_to(output, start);
+        output.extend(&self.line[start - self.shown_prompt_width..]);
+        output.push_str("\x1b[J");
+        self.note_printed_until(self.shown_prompt_width + self.line.len());
+        self.move_to(output, self.shown_prompt_width + self.cursor);
+    }
+    /// Moves the cursor by rows and to a column, `offset` being counted in chars from the start of the prompt
+    ///
+    /// Clamped to the first visible row
+    fn move_to(&mut self, output: &mut String, offset: usize) {
+        let offset = offset.max(self.get_first_visible_offset());
+        let (row, column) = self.get_screen_position(self.screen_cursor);
+        let (new_row, new_column) = self.get_screen_position(offset);
+        if new_row < row {
+            let _ = write!(output, "\x1b[{}A", row - new_row);
+        } else if new_row > row {
+            let _ = write!(output, "\x1b[{}B", new_row - row);
+        }
+        if new_column != column {
+            let _ = write!(output, "\x1b[{}G", new_column + 1);
+        }
+        self.screen_cursor = offset;
+    }
+    /// Follows the cursor after printing up to `offset`, the screen scrolling once the cursor goes past the last
+    /// row, as the console wraps right after the last column
+    fn note_printed_until(&mut self, offset: usize) {
+        self.screen_cursor = offset;
+        let (row, _) = self.get_screen_position(offset);
+        let screen_row = self.start_row + row as isize;
+        let last_row = self.height as isize - 1;
+        if screen_row > last_row {
+            self.start_row -= screen_row - last_row;
+        }
+    }
+    /// The offset of the first char on screen, past the start of the prompt once scrolled off
+    const fn get_first_visible_offset(&self) -> usize {
+        if self.start_row >= 0 {
+            0
+        } else {
+            self.start_row.unsigned_abs() * self.width - self.start_column
+        }
+    }
+    /// The row is relative to the row of the prompt
+    const fn get_screen_position(&self, offset: usize) -> (usize, usize) {
+        let position = self.start_column + offset;
+        (position / self.width, position % self.width)
+    }
+    /// Where the cursor is, the prompt being printed from there
+    fn read_start_position(&mut self) {
+        (self.width, self.height, self.start_row, self.start_column) =
+            console::lock().map_or((DEFAULT_WIDTH, DEFAULT_HEIGHT, 0, 0), |console| {
+                (
+                    console.output_width(),
+                    console.output_height(),
+                    console.output_cursor_row() as isize,
+                    console.output_cursor_column(),
+                )
+            });
+    }
+}
+
+/// The chars of `s` but its escape sequences, which have no width
+fn get_visible_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut is_in_sequence = false;
+    for c in s.chars() {
+        match c {
+            '\x1b' => is_in_sequence = true,
+            '\u{40}'..='\u{7e}' if is_in_sequence && c != '[' => is_in_sequence = false,
+            _ if is_in_sequence || c.is_control() => {}
+            _ => width += 1,
+        }
+    }
+    width
+}
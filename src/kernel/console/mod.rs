@@ -1,93 +1,351 @@
-use crate::kernel::console::char_buffer::{CharBuffer, CharColors, PrintableChar};
-use crate::kernel::native_graphics::{FrameBuffer, Pixel};
-use core::fmt::Write;
+use crate::kernel::console::cursor::CursorShape;
+use crate::kernel::console::font::{Font, FontScale};
+use crate::kernel::console::terminal::Terminal;
+use crate::kernel::cpu;
+use crate::kernel::keyboard::{KeyCode, KeyEvent};
+use crate::kernel::logger;
+use crate::kernel::memory::heap;
+use crate::kernel::native_graphics::FrameBuffer;
+use crate::kernel::serial;
+use alloc::collections::TryReserveError;
+use core::fmt::{Arguments, Display, Write};
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
 use core::panic::PanicInfo;
+use core::time::Duration;
+use spin::{Mutex, MutexGuard};
 
+mod ansi;
 pub mod char_bitmaps;
 mod char_buffer;
+pub(crate) mod cursor;
+pub(crate) mod font;
+pub(crate) mod line_editor;
+pub(crate) mod psf;
+mod scrollback;
+mod terminal;
 
-impl CharColors {
-    const OUTPUT: Self = Self {
-        foreground: Pixel::rgb(223, 223, 223),
-        background: Pixel::rgb(32, 32, 32),
-    };
-    const PANIC: Self = Self {
-        foreground: Pixel::rgb(223, 223, 223),
-        background: Pixel::rgb(223, 0, 0),
-    };
-}
+/// Alt+F1 to Alt+F6 show them
+const TERMINAL_COUNT: usize = 6;
+/// The scrollbacks of all the terminals take up to this share of the heap
+const SCROLLBACK_HEAP_DIVISOR: usize = 4;
+/// Shown at boot, with the log lines
+const LOG_TERMINAL: usize = 0;
+/// Where `kprint!` and the panic messages print
+pub(crate) const OUTPUT_TERMINAL: usize = 1;
 
-#[derive(Clone, Copy, Debug)]
+/// Like on the Linux console
+pub(crate) const CURSOR_BLINK_PERIOD: Duration = Duration::from_millis(500);
+
+/// Virtual terminals, one of them being shown on the frame buffer at a time
+#[derive(Debug)]
 pub(crate) struct Console {
-    char_buffer: CharBuffer,
+    terminals: [Terminal; TERMINAL_COUNT],
+    shown_terminal: usize,
 }
 
 impl Console {
+    /// Shows the log terminal
     pub(crate) fn new(frame_buffer: FrameBuffer) -> Self {
-        let mut char_buffer = CharBuffer::new(frame_buffer);
-        char_buffer.set_char_colors(CharColors::OUTPUT);
-        Self { char_buffer }
+        let screen_resolution = frame_buffer.resolution;
+        let mut console = Self {
+            terminals: core::array::from_fn(|_| Terminal::new(screen_resolution)),
+            shown_terminal: LOG_TERMINAL,
+        };
+        console.terminals[LOG_TERMINAL].show(frame_buffer);
+        console
     }
 
-    pub(crate) fn enter_panic_mode(&mut self) {
-        self.char_buffer.set_char_colors(CharColors::PANIC);
+    pub(crate) fn frame_buffer(&self) -> &FrameBuffer {
+        self.terminals[self.shown_terminal]
+            .frame_buffer()
+            .expect("the shown terminal must have the frame buffer")
     }
-
-    pub(crate) fn print(&mut self, s: &str) {
-        for c in s.chars() {
-            match c {
-                '\n' => self.wrap_line(),
-                '\r' => self.go_to_line_start(),
-                '\t' => self.insert_tab(),
-                _ => self.print_char(c),
-            };
+    pub(crate) fn frame_buffer_mut(&mut self) -> &mut FrameBuffer {
+        self.terminals[self.shown_terminal]
+            .frame_buffer_mut()
+            .expect("the shown terminal must have the frame buffer")
+    }
+    /// The columns of the output terminal, where `kprint!` prints
+    pub(crate) const fn output_width(&self) -> usize {
+        self.terminals[OUTPUT_TERMINAL].width()
+    }
+    /// The rows of the output terminal
+    pub(crate) const fn output_height(&self) -> usize {
+        self.terminals[OUTPUT_TERMINAL].height()
+    }
+    /// The row where the next char printed by `kprint!` goes
+    pub(crate) const fn output_cursor_row(&self) -> usize {
+        self.terminals[OUTPUT_TERMINAL].cursor_row()
+    }
+    /// Where the next char printed by `kprint!` goes on its row
+    pub(crate) const fn output_cursor_column(&self) -> usize {
+        self.terminals[OUTPUT_TERMINAL].cursor_column()
+    }
+    /// Keeps the text of every terminal, hidden ones too, so that the cursor can be drawn and the screen redrawn
+    ///
+    /// Needs the heap
+    pub(crate) fn allocate_cells(&mut self) -> Result<(), TryReserveError> {
+        for terminal in &mut self.terminals {
+            terminal.allocate_cells()?;
+        }
+        Ok(())
+    }
+    /// Keeps the lines scrolled off the top of every terminal, in a share of the heap
+    pub(crate) fn enable_scrollback(&mut self) {
+        let max_size = heap::get_stats().size / SCROLLBACK_HEAP_DIVISOR / TERMINAL_COUNT;
+        for terminal in &mut self.terminals {
+            terminal.enable_scrollback(max_size);
         }
     }
-    fn wrap_line(&mut self) {
-        self.char_buffer.go_down();
-        self.char_buffer.go_to_line_start();
+    /// Draws the whole screen again from its chars, after it was drawn over
+    pub(crate) fn redraw(&mut self) {
+        self.terminals[self.shown_terminal].redraw();
     }
-    fn go_to_line_start(&mut self) {
-        self.char_buffer.go_to_line_start();
+    /// Shows the terminal `index`, counted from 0, if it exists
+    pub(crate) fn show_terminal(&mut self, index: usize) {
+        if index == self.shown_terminal || index >= TERMINAL_COUNT {
+            return;
+        }
+        let frame_buffer = self.terminals[self.shown_terminal]
+            .hide()
+            .expect("the shown terminal must have the frame buffer");
+        self.terminals[index].show(frame_buffer);
+        self.shown_terminal = index;
     }
-    fn insert_tab(&mut self) {
-        const TAB_SIZE: usize = 4;
-        for _ in 0..TAB_SIZE {
-            self.char_buffer.put_char(PrintableChar::SPACE);
+    pub(crate) fn set_cursor_shape(&mut self, cursor_shape: CursorShape) {
+        for terminal in &mut self.terminals {
+            terminal.set_cursor_shape(cursor_shape);
         }
     }
-    fn print_char(&mut self, c: char) {
-        const MAX_UTF8_BYTE_COUNT: usize = 4;
-        let mut buffer = [0u8; MAX_UTF8_BYTE_COUNT];
-        let utf8_str = c.encode_utf8(&mut buffer);
-        for byte in utf8_str.bytes() {
-            let printable_char = PrintableChar::try_from(byte);
-            if let Ok(printable_char) = printable_char {
-                self.char_buffer.put_char(printable_char);
+    /// Shows or hides the cursor of the shown terminal, at every blink period
+    pub(crate) fn blink_cursor(&mut self) {
+        self.terminals[self.shown_terminal].blink_cursor();
+    }
+    /// Handles the console shortcuts, Alt+F1 to Alt+F6 to switch terminals, and Shift+PageUp and
+    /// Shift+PageDown to page through the scrollback
+    ///
+    /// Returns whether the key event was used
+    pub(crate) fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        if key_event.modifiers.is_alt() {
+            let index = match key_event.key_code {
+                KeyCode::F1 => 0,
+                KeyCode::F2 => 1,
+                KeyCode::F3 => 2,
+                KeyCode::F4 => 3,
+                KeyCode::F5 => 4,
+                KeyCode::F6 => 5,
+                _ => return false,
+            };
+            if key_event.is_pressed {
+                self.show_terminal(index);
             }
+            return true;
+        }
+        self.terminals[self.shown_terminal].handle_key_event(key_event)
+    }
+    /// Keeps the text, as much as fits the rows and columns changing with the size of the glyphs
+    pub(crate) fn set_font(&mut self, font: Font) {
+        for terminal in &mut self.terminals {
+            terminal.set_font(font);
+        }
+    }
+    /// Keeps the text, as much as fits the rows and columns changing with the size of the glyphs
+    pub(crate) fn set_font_scale(&mut self, font_scale: FontScale) {
+        for terminal in &mut self.terminals {
+            terminal.set_font_scale(font_scale);
         }
     }
-}
 
-struct PanicWriter(Console);
+    /// Panic messages are always shown, on the output terminal, with the panic colors
+    pub(crate) fn enter_panic_mode(&mut self) {
+        self.show_terminal(OUTPUT_TERMINAL);
+        self.terminals[OUTPUT_TERMINAL].enter_panic_mode();
+    }
 
-impl Write for PanicWriter {
+    /// Mirrored to the serial port, if any
+    pub(crate) fn print(&mut self, s: &str) {
+        serial::mirror(s);
+        self.terminals[OUTPUT_TERMINAL].print(s);
+    }
+    /// On the log terminal only, as the serial port has its own log sink
+    pub(crate) fn print_log_line(&mut self, line: &str) {
+        let terminal = &mut self.terminals[LOG_TERMINAL];
+        terminal.print(line);
+        terminal.print("\n");
+    }
+}
+
+impl Write for Console {
     /// Never fails
     fn write_str(&mut self, s: &str) -> core::fmt::Result {
-        let mut console = self.0;
-        console.enter_panic_mode();
-        console.print(s);
+        self.print(s);
         Ok(())
     }
 }
 
-pub(crate) struct DisposablePanicWriter(PanicWriter);
+struct PanicWriter<'a>(&'a mut Console);
 
-impl DisposablePanicWriter {
-    pub(crate) fn new(console: Console) -> Self {
+impl Write for PanicWriter<'_> {
+    /// Never fails
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.enter_panic_mode();
+        self.0.write_str(s)
+    }
+}
+
+pub(crate) struct DisposablePanicWriter<'a>(PanicWriter<'a>);
+
+impl<'a> DisposablePanicWriter<'a> {
+    pub(crate) fn new(console: &'a mut Console) -> Self {
         Self(PanicWriter(console))
     }
+    /// Heap statistics are printed too, as allocation failures end up here, then the last log lines
     pub(crate) fn panic(mut self, panic_info: &PanicInfo) {
+        const LOG_LINE_COUNT: usize = 8;
         write!(self.0, "\n{:?}", panic_info).unwrap();
+        if let Some(heap_stats) = heap::try_get_stats() {
+            write!(self.0, "\n{:?}", heap_stats).unwrap();
+        }
+        write!(self.0, "\nLast log lines :").unwrap();
+        logger::try_for_each_last_dmesg_line(LOG_LINE_COUNT, |line| {
+            write!(self.0, "\n{}", line).unwrap();
+        });
+    }
+    /// For fatal errors only, as the console stays in panic mode
+    pub(crate) fn report(mut self, report: &dyn Display) {
+        write!(self.0, "\n{}", report).unwrap();
+    }
+}
+
+/// The only console, shared by the kernel code, the interrupt handlers and the panic handler
+static CONSOLE: Mutex<Option<Console>> = Mutex::new(None);
+
+/// Gives access to the console, with interrupts disabled until dropped, so that no interrupt handler
+/// waits for the console forever
+pub(crate) struct ConsoleGuard {
+    guard: ManuallyDrop<MutexGuard<'static, Option<Console>>>,
+    were_interrupts_enabled: bool,
+}
+
+impl ConsoleGuard {
+    /// Returns `None`, with interrupts enabled again, if there is no console
+    fn new(
+        guard: MutexGuard<'static, Option<Console>>,
+        were_interrupts_enabled: bool,
+    ) -> Option<Self> {
+        let console_guard = Self {
+            guard: ManuallyDrop::new(guard),
+            were_interrupts_enabled,
+        };
+        console_guard.guard.is_some().then_some(console_guard)
+    }
+}
+
+impl Deref for ConsoleGuard {
+    type Target = Console;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.as_ref().expect("checked when locking")
+    }
+}
+
+impl DerefMut for ConsoleGuard {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.as_mut().expect("checked when locking")
+    }
+}
+
+impl Drop for ConsoleGuard {
+    fn drop(&mut self) {
+        // Safe : the guard is never used again
+        unsafe {
+            ManuallyDrop::drop(&mut self.guard);
+        }
+        if self.were_interrupts_enabled {
+            cpu::enable_interrupts();
+        }
     }
 }
+
+/// Makes `console` the one used by `lock`, `kprint!`, the log lines and the panic handler
+pub(crate) fn install(console: Console) {
+    cpu::without_interrupts(|| *CONSOLE.lock() = Some(console));
+}
+
+/// Waits for the console, and returns `None` if none is installed
+///
+/// Must not be called while holding the console, nor while logging to the screen, which would wait forever
+pub(crate) fn lock() -> Option<ConsoleGuard> {
+    let were_interrupts_enabled = cpu::are_interrupts_enabled();
+    cpu::disable_interrupts();
+    ConsoleGuard::new(CONSOLE.lock(), were_interrupts_enabled)
+}
+
+/// Returns `None` if the console is held, or if none is installed
+pub(crate) fn try_lock() -> Option<ConsoleGuard> {
+    let were_interrupts_enabled = cpu::are_interrupts_enabled();
+    cpu::disable_interrupts();
+    match CONSOLE.try_lock() {
+        Some(guard) => ConsoleGuard::new(guard, were_interrupts_enabled),
+        None => {
+            if were_interrupts_enabled {
+                cpu::enable_interrupts();
+            }
+            None
+        }
+    }
+}
+
+/// Takes the console even if it's held, which may have been interrupted in the middle of a print
+///
+/// # Safety
+/// The code holding the console must never use it again, which is the case once the kernel panics
+pub(crate) unsafe fn force_lock() -> Option<ConsoleGuard> {
+    if CONSOLE.is_locked() {
+        CONSOLE.force_unlock();
+    }
+    lock()
+}
+
+/// Timer interrupt handler, skipping the blink if the console is held by the interrupted code
+pub(crate) fn blink_cursor() {
+    if let Some(mut console) = try_lock() {
+        console.blink_cursor();
+    }
+}
+
+/// Log sink drawing on the log terminal only, as the serial port has its own sink
+pub(crate) fn log_on_screen(line: &str) {
+    if let Some(mut console) = lock() {
+        console.print_log_line(line);
+    }
+}
+
+/// Prints to the installed console, if any
+#[doc(hidden)]
+pub(crate) fn print_fmt(arguments: Arguments) {
+    if let Some(mut console) = lock() {
+        let _ = console.write_fmt(arguments);
+    }
+}
+
+/// Like `print!`, on the console
+macro_rules! kprint {
+    ($($argument:tt)*) => {
+        $crate::kernel::console::print_fmt(format_args!($($argument)*))
+    };
+}
+
+/// Like `println!`, on the console
+macro_rules! kprintln {
+    () => {
+        $crate::kernel::console::print_fmt(format_args!("\n"))
+    };
+    ($($argument:tt)*) => {
+        $crate::kernel::console::print_fmt(format_args!("{}\n", format_args!($($argument)*)))
+    };
+}
+
+pub(crate) use kprint;
+pub(crate) use kprintln;
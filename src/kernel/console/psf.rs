@@ -0,0 +1,208 @@
+use crate::kernel::native_graphics::Resolution;
+use alloc::vec::Vec;
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_HEADER_SIZE: usize = 4;
+const PSF1_MODE_512: u8 = 0x01;
+const PSF1_MODE_HAS_TABLE: u8 = 0x02;
+/// Implies a Unicode table too
+const PSF1_MODE_HAS_SEQUENCES: u8 = 0x04;
+const PSF1_GLYPH_WIDTH: usize = 8;
+const PSF1_SEPARATOR: u16 = 0xffff;
+const PSF1_SEQUENCE_START: u16 = 0xfffe;
+
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const PSF2_HEADER_SIZE: usize = 32;
+const PSF2_FLAG_HAS_UNICODE_TABLE: usize = 0x01;
+const PSF2_SEPARATOR: u8 = 0xff;
+const PSF2_SEQUENCE_START: u8 = 0xfe;
+
+/// Larger glyphs would leave less than 10 columns or 3 rows on the smallest supported screens
+const MAX_GLYPH_RESOLUTION: Resolution = Resolution {
+    horizontal: 32,
+    vertical: 64,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum PsfError {
+    /// Neither a PSF1 nor a PSF2 font
+    UnknownMagic,
+    /// No glyph, too many of them, or glyphs too small for their announced resolution
+    InvalidHeader,
+    /// The file ends before the glyphs or the Unicode table announced by the header
+    Truncated,
+    /// Glyphs may be up to 32x64
+    UnsupportedGlyphResolution,
+}
+
+/// A PC Screen Font, version 1 or 2, like the Terminus and Spleen fonts of the Linux console
+///
+/// Copied into every terminal, its glyphs and Unicode table living as long as the kernel
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PsfFont {
+    glyphs: &'static [u8],
+    glyph_count: usize,
+    bytes_per_glyph: usize,
+    glyph_resolution: Resolution,
+    /// Sorted by char, and empty if the font has none, its glyphs then being indexed by code point
+    unicode_table: &'static [(char, usize)],
+}
+
+impl PsfFont {
+    /// `bytes` are read from the ESP through the `font=<path>` load option, as no PSF font is embedded
+    ///
+    /// Only the single chars of the Unicode table are kept, as sequences of combining chars can't be drawn in one cell
+    pub(crate) fn parse(bytes: &'static [u8]) -> Result<Self, PsfError> {
+        if bytes.starts_with(&PSF1_MAGIC) {
+            Self::parse_psf1(bytes)
+        } else if bytes.starts_with(&PSF2_MAGIC) {
+            Self::parse_psf2(bytes)
+        } else {
+            Err(PsfError::UnknownMagic)
+        }
+    }
+
+    pub(crate) const fn glyph_resolution(&self) -> Resolution {
+        self.glyph_resolution
+    }
+    pub(crate) const fn glyph_count(&self) -> usize {
+        self.glyph_count
+    }
+
+    pub(super) fn find_glyph_index(&self, c: char) -> Option<usize> {
+        if self.unicode_table.is_empty() {
+            return Some(c as usize).filter(|&glyph_index| glyph_index < self.glyph_count);
+        }
+        self.unicode_table
+            .binary_search_by_key(&c, |&(table_char, _)| table_char)
+            .ok()
+            .map(|table_index| self.unicode_table[table_index].1)
+    }
+    /// # Panics
+    /// Panics if `glyph_index` is not below the glyph count
+    pub(super) fn get_bitmap(&self, glyph_index: usize) -> &'static [u8] {
+        let start = glyph_index * self.bytes_per_glyph;
+        &self.glyphs[start..start + self.bytes_per_glyph]
+    }
+
+    fn parse_psf1(bytes: &'static [u8]) -> Result<Self, PsfError> {
+        let header = bytes.get(..PSF1_HEADER_SIZE).ok_or(PsfError::Truncated)?;
+        let mode = header[2];
+        let height = header[3] as usize;
+        let glyph_count = if mode & PSF1_MODE_512 != 0 { 512 } else { 256 };
+        let glyphs_end = PSF1_HEADER_SIZE + glyph_count * height;
+        let glyphs = bytes
+            .get(PSF1_HEADER_SIZE..glyphs_end)
+            .ok_or(PsfError::Truncated)?;
+        let mut unicode_table = Vec::new();
+        if mode & (PSF1_MODE_HAS_TABLE | PSF1_MODE_HAS_SEQUENCES) != 0 {
+            let mut code_units = bytes[glyphs_end..]
+                .chunks_exact(2)
+                .map(|code_unit| u16::from_le_bytes([code_unit[0], code_unit[1]]));
+            for glyph_index in 0..glyph_count {
+                let mut is_in_sequence = false;
+                loop {
+                    match code_units.next().ok_or(PsfError::Truncated)? {
+                        PSF1_SEPARATOR => break,
+                        PSF1_SEQUENCE_START => is_in_sequence = true,
+                        _ if is_in_sequence => {}
+                        code_unit => {
+                            if let Some(c) = char::from_u32(code_unit as u32) {
+                                unicode_table.push((c, glyph_index));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let glyph_resolution = Resolution {
+            horizontal: PSF1_GLYPH_WIDTH,
+            vertical: height,
+        };
+        Self::new(glyphs, glyph_count, height, glyph_resolution, unicode_table)
+    }
+
+    fn parse_psf2(bytes: &'static [u8]) -> Result<Self, PsfError> {
+        let header = bytes.get(..PSF2_HEADER_SIZE).ok_or(PsfError::Truncated)?;
+        let read_field = |offset: usize| {
+            let field = [
+                header[offset],
+                header[offset + 1],
+                header[offset + 2],
+                header[offset + 3],
+            ];
+            u32::from_le_bytes(field) as usize
+        };
+        let header_size = read_field(8);
+        let flags = read_field(12);
+        let glyph_count = read_field(16);
+        let bytes_per_glyph = read_field(20);
+        let glyph_resolution = Resolution {
+            horizontal: read_field(28),
+            vertical: read_field(24),
+        };
+        let glyphs_end = glyph_count
+            .checked_mul(bytes_per_glyph)
+            .and_then(|glyphs_size| glyphs_size.checked_add(header_size))
+            .ok_or(PsfError::InvalidHeader)?;
+        let glyphs = bytes
+            .get(header_size..glyphs_end)
+            .ok_or(PsfError::Truncated)?;
+        let mut unicode_table = Vec::new();
+        if flags & PSF2_FLAG_HAS_UNICODE_TABLE != 0 {
+            let mut entries = bytes[glyphs_end..].split(|&byte| byte == PSF2_SEPARATOR);
+            for glyph_index in 0..glyph_count {
+                let entry = entries.next().ok_or(PsfError::Truncated)?;
+                let single_chars = entry
+                    .split(|&byte| byte == PSF2_SEQUENCE_START)
+                    .next()
+                    .unwrap_or_default();
+                if let Ok(single_chars) = core::str::from_utf8(single_chars) {
+                    unicode_table.extend(single_chars.chars().map(|c| (c, glyph_index)));
+                }
+            }
+            // The last entry must still be followed by its separator
+            entries.next().ok_or(PsfError::Truncated)?;
+        }
+        Self::new(
+            glyphs,
+            glyph_count,
+            bytes_per_glyph,
+            glyph_resolution,
+            unicode_table,
+        )
+    }
+
+    fn new(
+        glyphs: &'static [u8],
+        glyph_count: usize,
+        bytes_per_glyph: usize,
+        glyph_resolution: Resolution,
+        mut unicode_table: Vec<(char, usize)>,
+    ) -> Result<Self, PsfError> {
+        let Resolution {
+            horizontal: width,
+            vertical: height,
+        } = glyph_resolution;
+        if width == 0
+            || height == 0
+            || width > MAX_GLYPH_RESOLUTION.horizontal
+            || height > MAX_GLYPH_RESOLUTION.vertical
+        {
+            return Err(PsfError::UnsupportedGlyphResolution);
+        }
+        if glyph_count == 0 || bytes_per_glyph < width.div_ceil(8) * height {
+            return Err(PsfError::InvalidHeader);
+        }
+        // Stable, so that the first glyph given for a char is kept
+        unicode_table.sort_by_key(|&(c, _)| c);
+        unicode_table.dedup_by_key(|&mut (c, _)| c);
+        Ok(Self {
+            glyphs,
+            glyph_count,
+            bytes_per_glyph,
+            glyph_resolution,
+            unicode_table: unicode_table.leak(),
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use crate::kernel::console::char_buffer::OptionalColoredChar;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::mem;
+
+/// The lines scrolled off the top of the screen, the oldest ones being dropped first once the size limit is reached
+#[derive(Debug)]
+pub(super) struct Scrollback {
+    /// The newest line is at the back, kept without its trailing blank cells
+    lines: VecDeque<Vec<OptionalColoredChar>>,
+    /// The bytes taken by the lines, counting their headers in `lines`
+    size: usize,
+    /// Given when enabled, as a share of the heap
+    max_size: usize,
+    /// How many lines back the screen shows, 0 showing the live screen
+    view_offset: usize,
+    /// No line is kept until enabled, as they need the heap
+    is_enabled: bool,
+}
+
+impl Scrollback {
+    pub(super) const fn new() -> Self {
+        Self {
+            lines: VecDeque::new(),
+            size: 0,
+            max_size: 0,
+            view_offset: 0,
+            is_enabled: false,
+        }
+    }
+    pub(super) fn enable(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        self.is_enabled = true;
+    }
+
+    pub(super) const fn view_offset(&self) -> usize {
+        self.view_offset
+    }
+    /// Clamped to the lines kept, returns whether the view moved
+    pub(super) fn set_view_offset(&mut self, view_offset: usize) -> bool {
+        let view_offset = view_offset.min(self.lines.len());
+        let has_moved = view_offset != self.view_offset;
+        self.view_offset = view_offset;
+        has_moved
+    }
+
+    /// Lines are silently dropped when the heap is full, or while disabled
+    pub(super) fn push_line(&mut self, cells: &[OptionalColoredChar]) {
+        if !self.is_enabled {
+            return;
+        }
+        let length = cells
+            .iter()
+            .rposition(|cell| !cell.is_blank())
+            .map_or(0, |last_index| last_index + 1);
+        let line_size = get_line_size(length);
+        if line_size > self.max_size {
+            return;
+        }
+        while self.size + line_size > self.max_size {
+            let Some(oldest_line) = self.lines.pop_front() else {
+                break;
+            };
+            self.size -= get_line_size(oldest_line.len());
+        }
+        self.view_offset = self.view_offset.min(self.lines.len());
+        let mut line = Vec::new();
+        if line.try_reserve_exact(length).is_err() || self.lines.try_reserve(1).is_err() {
+            return;
+        }
+        line.extend_from_slice(&cells[..length]);
+        self.lines.push_back(line);
+        self.size += line_size;
+    }
+    /// Counted from the first line shown when the view is `view_offset` lines back
+    pub(super) fn get_viewed_line(&self, row: usize) -> Option<&[OptionalColoredChar]> {
+        let index = (self.lines.len() + row).checked_sub(self.view_offset)?;
+        self.lines.get(index).map(Vec::as_slice)
+    }
+}
+
+const fn get_line_size(length: usize) -> usize {
+    mem::size_of::<Vec<OptionalColoredChar>>() + length * mem::size_of::<OptionalColoredChar>()
+}
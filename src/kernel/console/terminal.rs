@@ -0,0 +1,320 @@
+use crate::kernel::console::ansi::{Action, ControlSequence, EscapeParser, GraphicRendition};
+use crate::kernel::console::char_buffer::{
+    CharBuffer, CharColors, CharPosition, EraseMode, PrintableChar,
+};
+use crate::kernel::console::cursor::CursorShape;
+use crate::kernel::console::font::{Font, FontScale};
+use crate::kernel::keyboard::{KeyCode, KeyEvent};
+use crate::kernel::native_graphics::{FrameBuffer, Pixel, Resolution};
+use alloc::collections::TryReserveError;
+
+impl CharColors {
+    const OUTPUT: Self = Self {
+        foreground: Pixel::rgb(223, 223, 223),
+        background: Pixel::rgb(32, 32, 32),
+    };
+    const PANIC: Self = Self {
+        foreground: Pixel::rgb(223, 223, 223),
+        background: Pixel::rgb(223, 0, 0),
+    };
+}
+
+/// Draws text on the frame buffer when shown, interpreting the VT100 escape sequences, like `ESC [ 31 m` for red text
+#[derive(Debug)]
+pub(super) struct Terminal {
+    char_buffer: CharBuffer,
+    escape_parser: EscapeParser,
+    /// The colors of the SGR default color, and of the erased chars
+    default_colors: CharColors,
+    graphic_rendition: GraphicRendition,
+    saved_cursor: Option<(CharPosition, GraphicRendition)>,
+}
+
+impl Terminal {
+    /// Hidden until `show`
+    pub(super) fn new(screen_resolution: Resolution) -> Self {
+        let mut terminal = Self {
+            char_buffer: CharBuffer::new(screen_resolution),
+            escape_parser: EscapeParser::new(),
+            default_colors: CharColors::OUTPUT,
+            graphic_rendition: GraphicRendition::DEFAULT,
+            saved_cursor: None,
+        };
+        terminal.apply_graphic_rendition();
+        terminal
+    }
+
+    /// `None` while hidden
+    pub(super) fn frame_buffer(&self) -> Option<&FrameBuffer> {
+        self.char_buffer.frame_buffer()
+    }
+    /// `None` while hidden
+    pub(super) fn frame_buffer_mut(&mut self) -> Option<&mut FrameBuffer> {
+        self.char_buffer.frame_buffer_mut()
+    }
+    pub(super) const fn width(&self) -> usize {
+        self.char_buffer.width()
+    }
+    pub(super) const fn height(&self) -> usize {
+        self.char_buffer.height()
+    }
+    pub(super) const fn cursor_row(&self) -> usize {
+        self.char_buffer.cursor_position().row
+    }
+    pub(super) const fn cursor_column(&self) -> usize {
+        self.char_buffer.cursor_position().column
+    }
+    /// Draws the whole terminal on the frame buffer, which it keeps drawing on until hidden
+    pub(super) fn show(&mut self, frame_buffer: FrameBuffer) {
+        self.char_buffer.show(frame_buffer);
+        self.char_buffer.show_cursor();
+        self.present();
+    }
+    /// Gives the frame buffer back, if shown
+    pub(super) fn hide(&mut self) -> Option<FrameBuffer> {
+        self.char_buffer.hide()
+    }
+    /// Needs the heap
+    pub(super) fn allocate_cells(&mut self) -> Result<(), TryReserveError> {
+        self.char_buffer.allocate_cells()
+    }
+    /// In up to `max_size` bytes
+    pub(super) fn enable_scrollback(&mut self, max_size: usize) {
+        self.char_buffer.enable_scrollback(max_size);
+    }
+    /// Draws the whole screen again from its chars, after it was drawn over
+    pub(super) fn redraw(&mut self) {
+        self.char_buffer.redraw();
+        self.char_buffer.show_cursor();
+        self.present();
+    }
+    pub(super) fn set_cursor_shape(&mut self, cursor_shape: CursorShape) {
+        self.char_buffer.set_cursor_shape(cursor_shape);
+        self.char_buffer.show_cursor();
+        self.present();
+    }
+    /// Shows or hides the cursor, at every blink period
+    pub(super) fn blink_cursor(&mut self) {
+        self.char_buffer.blink_cursor();
+        self.present();
+    }
+    /// Handles Shift+PageUp and Shift+PageDown, to page through the scrollback
+    ///
+    /// Returns whether the key event was used
+    pub(super) fn handle_key_event(&mut self, key_event: KeyEvent) -> bool {
+        if !key_event.modifiers.is_shift() {
+            return false;
+        }
+        let page_row_count = self.char_buffer.height() / 2;
+        let view_offset = self.char_buffer.view_offset();
+        let view_offset = match key_event.key_code {
+            KeyCode::PageUp => view_offset + page_row_count,
+            KeyCode::PageDown => view_offset.saturating_sub(page_row_count),
+            _ => return false,
+        };
+        if key_event.is_pressed {
+            self.char_buffer.set_view_offset(view_offset);
+            self.char_buffer.show_cursor();
+            self.present();
+        }
+        true
+    }
+    /// Keeps the text, as much as fits the rows and columns changing with the size of the glyphs
+    pub(super) fn set_font(&mut self, font: Font) {
+        self.saved_cursor = None;
+        self.char_buffer.hide_cursor();
+        self.char_buffer.set_font(font);
+        self.char_buffer.show_cursor();
+    }
+    /// Keeps the text, as much as fits the rows and columns changing with the size of the glyphs
+    pub(super) fn set_font_scale(&mut self, font_scale: FontScale) {
+        self.saved_cursor = None;
+        self.char_buffer.hide_cursor();
+        self.char_buffer.set_font_scale(font_scale);
+        self.char_buffer.show_cursor();
+    }
+
+    /// Panic messages are always drawn with the panic colors, whatever the escape sequences printed before
+    pub(super) fn enter_panic_mode(&mut self) {
+        self.escape_parser = EscapeParser::new();
+        self.default_colors = CharColors::PANIC;
+        self.graphic_rendition = GraphicRendition::DEFAULT;
+        self.apply_graphic_rendition();
+    }
+
+    /// Shows the live screen again if scrolled back
+    ///
+    /// The cursor is shown afterwards, even if it had blinked off, so that it doesn't disappear while typing
+    pub(super) fn print(&mut self, s: &str) {
+        self.char_buffer.hide_cursor();
+        self.char_buffer.set_view_offset(0);
+        for c in s.chars() {
+            match self.escape_parser.advance(c) {
+                Some(Action::Print(c)) => self.print_char(c),
+                Some(Action::Execute(c)) => self.execute(c),
+                Some(Action::Escape(c)) => self.escape(c),
+                Some(Action::Control(sequence)) => self.control(sequence),
+                None => {}
+            }
+        }
+        self.char_buffer.show_cursor();
+        // Once per print, so that scrolling many lines costs a single copy to the video memory
+        self.present();
+    }
+
+    /// Nothing to present while hidden
+    fn present(&mut self) {
+        if let Some(frame_buffer) = self.frame_buffer_mut() {
+            frame_buffer.present();
+        }
+    }
+    /// Other control chars are ignored
+    fn execute(&mut self, c: char) {
+        match c {
+            '\n' => self.wrap_line(),
+            '\r' => self.go_to_line_start(),
+            '\t' => self.insert_tab(),
+            '\u{8}' => self.char_buffer.go_left_by(1),
+            _ => {}
+        }
+    }
+    /// Other escape sequences are ignored
+    fn escape(&mut self, c: char) {
+        match c {
+            '7' => self.save_cursor(),
+            '8' => self.restore_cursor(),
+            'D' => self.char_buffer.go_down(),
+            'E' => self.wrap_line(),
+            'M' => self.char_buffer.go_up(),
+            'c' => self.reset(),
+            _ => {}
+        }
+    }
+    /// Other control sequences and DEC private modes are ignored
+    fn control(&mut self, sequence: ControlSequence) {
+        if sequence.is_private {
+            self.set_private_mode(sequence);
+            return;
+        }
+        let count = sequence.get(0, 1) as usize;
+        match sequence.final_char {
+            'A' => self.char_buffer.go_up_by(count),
+            'B' => self.char_buffer.go_down_by(count),
+            'C' => self.char_buffer.go_right_by(count),
+            'D' => self.char_buffer.go_left_by(count),
+            'E' => {
+                self.char_buffer.go_down_by(count);
+                self.go_to_line_start();
+            }
+            'F' => {
+                self.char_buffer.go_up_by(count);
+                self.go_to_line_start();
+            }
+            'G' => {
+                let row = self.char_buffer.cursor_position().row;
+                self.go_to(row + 1, count);
+            }
+            'H' | 'f' => self.go_to(sequence.get(0, 1) as usize, sequence.get(1, 1) as usize),
+            'J' => self
+                .char_buffer
+                .erase_in_display(Self::get_erase_mode(sequence)),
+            'K' => self
+                .char_buffer
+                .erase_in_line(Self::get_erase_mode(sequence)),
+            'L' => self.char_buffer.insert_lines(count),
+            'M' => self.char_buffer.delete_lines(count),
+            'P' => self.char_buffer.delete_chars(count),
+            'S' => self.char_buffer.scroll_up(count),
+            'T' => self.char_buffer.scroll_down(count),
+            'X' => self.char_buffer.erase_chars(count),
+            '@' => self.char_buffer.insert_chars(count),
+            'm' => {
+                self.graphic_rendition.select(sequence.parameters());
+                self.apply_graphic_rendition();
+            }
+            'r' => {
+                let height = self.char_buffer.height() as u16;
+                let top = sequence.get(0, 1) as usize;
+                let bottom = sequence.get(1, height) as usize;
+                self.char_buffer.set_scroll_region(top - 1, bottom - 1);
+            }
+            's' => self.save_cursor(),
+            'u' => self.restore_cursor(),
+            _ => {}
+        }
+    }
+
+    /// Only DECTCEM, showing or hiding the cursor, is supported
+    fn set_private_mode(&mut self, sequence: ControlSequence) {
+        const TEXT_CURSOR_ENABLE_MODE: u16 = 25;
+        let is_set = match sequence.final_char {
+            'h' => true,
+            'l' => false,
+            _ => return,
+        };
+        if sequence.parameters().contains(&TEXT_CURSOR_ENABLE_MODE) {
+            self.char_buffer.set_cursor_enabled(is_set);
+        }
+    }
+    fn get_erase_mode(sequence: ControlSequence) -> EraseMode {
+        match sequence.get(0, 0) {
+            1 => EraseMode::ToStart,
+            2 => EraseMode::All,
+            _ => EraseMode::ToEnd,
+        }
+    }
+    /// Rows and columns are counted from 1, like in the escape sequences
+    fn go_to(&mut self, row: usize, column: usize) {
+        self.char_buffer.go_to(CharPosition {
+            row: row.saturating_sub(1),
+            column: column.saturating_sub(1),
+        });
+    }
+    fn save_cursor(&mut self) {
+        self.saved_cursor = Some((self.char_buffer.cursor_position(), self.graphic_rendition));
+    }
+    /// Goes home with the default rendition if nothing was saved
+    fn restore_cursor(&mut self) {
+        let (cursor_position, graphic_rendition) = self.saved_cursor.unwrap_or((
+            CharPosition { row: 0, column: 0 },
+            GraphicRendition::DEFAULT,
+        ));
+        self.char_buffer.go_to(cursor_position);
+        self.graphic_rendition = graphic_rendition;
+        self.apply_graphic_rendition();
+    }
+    fn reset(&mut self) {
+        self.graphic_rendition = GraphicRendition::DEFAULT;
+        self.saved_cursor = None;
+        self.apply_graphic_rendition();
+        let height = self.char_buffer.height();
+        self.char_buffer.set_scroll_region(0, height - 1);
+        self.char_buffer.set_cursor_enabled(true);
+        self.char_buffer.erase_in_display(EraseMode::All);
+    }
+    fn apply_graphic_rendition(&mut self) {
+        let char_colors = self.graphic_rendition.get_char_colors(self.default_colors);
+        self.char_buffer.set_char_colors(char_colors);
+        self.char_buffer
+            .set_underlined(self.graphic_rendition.is_underlined);
+    }
+
+    fn wrap_line(&mut self) {
+        self.char_buffer.go_to_line_start();
+        self.char_buffer.go_down();
+    }
+    fn go_to_line_start(&mut self) {
+        self.char_buffer.go_to_line_start();
+    }
+    fn insert_tab(&mut self) {
+        const TAB_SIZE: usize = 4;
+        for _ in 0..TAB_SIZE {
+            self.char_buffer.put_char(PrintableChar::SPACE);
+        }
+    }
+    fn print_char(&mut self, c: char) {
+        if let Ok(printable_char) = PrintableChar::try_from(c) {
+            self.char_buffer.put_char(printable_char);
+        }
+    }
+}
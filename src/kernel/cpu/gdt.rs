@@ -0,0 +1,120 @@
+use crate::kernel::memory::paging;
+use core::arch::asm;
+use core::mem;
+use core::ptr::addr_of;
+
+pub(crate) const KERNEL_CODE_SELECTOR: u16 = 0x08;
+const KERNEL_DATA_SELECTOR: u16 = 0x10;
+const TSS_SELECTOR: u16 = 0x18;
+
+/// Interrupt stack table indices, starting from 1 as 0 means "no stack switch"
+pub(crate) const DOUBLE_FAULT_IST_INDEX: u8 = 1;
+pub(crate) const NON_MASKABLE_INTERRUPT_IST_INDEX: u8 = 2;
+pub(crate) const MACHINE_CHECK_IST_INDEX: u8 = 3;
+const IST_STACK_PAGE_COUNT: u64 = 5;
+
+/// 64-bit, present, ring 0 code segment
+const KERNEL_CODE_DESCRIPTOR: u64 = 0x00AF_9A00_0000_FFFF;
+/// Present, ring 0, writable data segment
+const KERNEL_DATA_DESCRIPTOR: u64 = 0x00CF_9200_0000_FFFF;
+const AVAILABLE_TSS_TYPE: u64 = 0x9;
+const PRESENT: u64 = 1 << 47;
+
+#[repr(C, packed(4))]
+struct TaskStateSegment {
+    reserved_1: u32,
+    privilege_stack_table: [u64; 3],
+    reserved_2: u64,
+    /// Entry `n` is the stack top for the IST index `n + 1`
+    interrupt_stack_table: [u64; 7],
+    reserved_3: u64,
+    reserved_4: u16,
+    io_map_base_address: u16,
+}
+
+#[repr(C, packed(2))]
+pub(crate) struct DescriptorTablePointer {
+    pub(crate) limit: u16,
+    pub(crate) base: u64,
+}
+
+static mut TSS: TaskStateSegment = TaskStateSegment {
+    reserved_1: 0,
+    privilege_stack_table: [0; 3],
+    reserved_2: 0,
+    interrupt_stack_table: [0; 7],
+    reserved_3: 0,
+    reserved_4: 0,
+    // No IO permission bitmap
+    io_map_base_address: mem::size_of::<TaskStateSegment>() as u16,
+};
+
+/// Null descriptor, kernel code, kernel data, and the 16-byte TSS descriptor
+static mut GDT: [u64; 5] = [0, KERNEL_CODE_DESCRIPTOR, KERNEL_DATA_DESCRIPTOR, 0, 0];
+
+fn get_tss_descriptor(tss_address: u64) -> [u64; 2] {
+    let limit = (mem::size_of::<TaskStateSegment>() - 1) as u64;
+    let low = limit
+        | (tss_address & 0xFF_FFFF) << 16
+        | AVAILABLE_TSS_TYPE << 40
+        | PRESENT
+        | (tss_address >> 24 & 0xFF) << 56;
+    let high = tss_address >> 32;
+    [low, high]
+}
+
+/// Replaces the UEFI GDT with the kernel one, whose TSS gives dedicated stacks to the double fault,
+/// non-maskable interrupt and machine check handlers
+///
+/// Must be called once, after the kernel address space is built
+///
+/// # Panics
+/// Panics if the interrupt stacks can't be allocated
+pub(crate) fn init() {
+    let ist_indices = [
+        DOUBLE_FAULT_IST_INDEX,
+        NON_MASKABLE_INTERRUPT_IST_INDEX,
+        MACHINE_CHECK_IST_INDEX,
+    ];
+    // Safe :
+    // - This is called once, before interrupts are enabled, so nothing else accesses the TSS nor the GDT
+    // - The segments match the UEFI flat segments, so the running code keeps working after the reload
+    unsafe {
+        for ist_index in ist_indices {
+            let stack_top = paging::allocate_kernel_stack(IST_STACK_PAGE_COUNT)
+                .expect("cannot allocate an interrupt stack");
+            TSS.interrupt_stack_table[ist_index as usize - 1] = stack_top;
+        }
+        let [tss_low, tss_high] = get_tss_descriptor(addr_of!(TSS) as u64);
+        GDT[3] = tss_low;
+        GDT[4] = tss_high;
+        let gdt_pointer = DescriptorTablePointer {
+            limit: (mem::size_of::<[u64; 5]>() - 1) as u16,
+            base: addr_of!(GDT) as u64,
+        };
+        asm!("lgdt [{}]", in(reg) &gdt_pointer, options(readonly, nostack, preserves_flags));
+        reload_segment_registers();
+        asm!("ltr {:x}", in(reg) TSS_SELECTOR, options(nomem, nostack, preserves_flags));
+    }
+}
+
+/// # Safety
+/// The kernel GDT must be loaded
+unsafe fn reload_segment_registers() {
+    asm!(
+        "push {code}",
+        "lea {tmp}, [rip + 3f]",
+        "push {tmp}",
+        "retfq",
+        "3:",
+        "mov ds, {data:x}",
+        "mov es, {data:x}",
+        "mov fs, {data:x}",
+        "mov gs, {data:x}",
+        "mov ss, {data:x}",
+        code = in(reg) KERNEL_CODE_SELECTOR as u64,
+        data = in(reg) KERNEL_DATA_SELECTOR as u64,
+        tmp = out(reg) _,
+        options(preserves_flags),
+    );
+}
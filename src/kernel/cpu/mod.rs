@@ -0,0 +1,171 @@
+use core::arch::asm;
+use core::{mem, ptr};
+
+pub(crate) mod gdt;
+pub(crate) mod port;
+
+const EFER_MSR: u32 = 0xC000_0080;
+const EFER_NO_EXECUTE_ENABLE: u64 = 1 << 11;
+const CR0_WRITE_PROTECT: u64 = 1 << 16;
+const RFLAGS_INTERRUPT_ENABLE: u64 = 1 << 9;
+
+/// # Safety
+/// `msr` must be an existing model specific register
+pub(crate) unsafe fn read_msr(msr: u32) -> u64 {
+    let (low, high): (u32, u32);
+    asm!("rdmsr", in("ecx") msr, out("eax") low, out("edx") high, options(nomem, nostack, preserves_flags));
+    ((high as u64) << 32) | low as u64
+}
+
+/// # Safety
+/// `msr` must be an existing model specific register, and `value` mustn't break any memory safety assumption
+pub(crate) unsafe fn write_msr(msr: u32, value: u64) {
+    asm!("wrmsr", in("ecx") msr, in("eax") value as u32, in("edx") (value >> 32) as u32, options(nostack, preserves_flags));
+}
+
+fn read_cr0() -> u64 {
+    let value: u64;
+    // Safe : reading CR0 has no side effect
+    unsafe {
+        asm!("mov {}, cr0", out(reg) value, options(nomem, nostack, preserves_flags));
+    }
+    value
+}
+
+/// # Safety
+/// `value` mustn't break any memory safety assumption
+unsafe fn write_cr0(value: u64) {
+    asm!("mov cr0, {}", in(reg) value, options(nostack, preserves_flags));
+}
+
+/// Holds the faulting address after a page fault
+pub(crate) fn read_cr2() -> u64 {
+    let value: u64;
+    // Safe : reading CR2 has no side effect
+    unsafe {
+        asm!("mov {}, cr2", out(reg) value, options(nomem, nostack, preserves_flags));
+    }
+    value
+}
+
+pub(crate) fn read_cr3() -> u64 {
+    let value: u64;
+    // Safe : reading CR3 has no side effect
+    unsafe {
+        asm!("mov {}, cr3", out(reg) value, options(nomem, nostack, preserves_flags));
+    }
+    value
+}
+
+/// # Safety
+/// `pml4_address` must be the physical address of a PML4 mapping at least the running code, its stack and its data
+pub(crate) unsafe fn write_cr3(pml4_address: u64) {
+    asm!("mov cr3, {}", in(reg) pml4_address, options(nostack, preserves_flags));
+}
+
+pub(crate) fn invalidate_page(virtual_address: u64) {
+    // Safe : flushing a TLB entry only costs a page walk
+    unsafe {
+        asm!("invlpg [{}]", in(reg) virtual_address, options(nostack, preserves_flags));
+    }
+}
+
+/// Lets page table entries forbid execution, and forbids the kernel to write into read-only pages
+///
+/// # Safety
+/// No page table entry used by the running code may have the no-execute bit set while not being meant so
+pub(crate) unsafe fn enable_page_protections() {
+    write_msr(EFER_MSR, read_msr(EFER_MSR) | EFER_NO_EXECUTE_ENABLE);
+    write_cr0(read_cr0() | CR0_WRITE_PROTECT);
+}
+
+pub(crate) fn enable_interrupts() {
+    // Safe : enabling interrupts has no memory effect, the handlers save everything they change
+    unsafe {
+        asm!("sti", options(nomem, nostack));
+    }
+}
+
+pub(crate) fn disable_interrupts() {
+    // Safe : disabling interrupts has no memory effect
+    unsafe {
+        asm!("cli", options(nomem, nostack));
+    }
+}
+
+pub(crate) fn are_interrupts_enabled() -> bool {
+    let rflags: u64;
+    // Safe : reading the flags has no side effect
+    unsafe {
+        asm!("pushfq", "pop {}", out(reg) rflags, options(nomem, preserves_flags));
+    }
+    rflags & RFLAGS_INTERRUPT_ENABLE != 0
+}
+
+/// Runs `f` with interrupts disabled, then enables them again if they were
+///
+/// Locks that interrupt handlers may take must be taken through this, or a handler could wait for them forever
+pub(crate) fn without_interrupts<R>(f: impl FnOnce() -> R) -> R {
+    let were_enabled = are_interrupts_enabled();
+    disable_interrupts();
+    let result = f();
+    if were_enabled {
+        enable_interrupts();
+    }
+    result
+}
+
+/// Enables interrupts and waits for the next one
+///
+/// Checking for some work with interrupts disabled, then calling this, can't miss an interrupt in between,
+/// as `sti` only takes effect after the next instruction
+pub(crate) fn enable_interrupts_and_halt() {
+    // Safe : the handlers save everything they change, and the memory they change is reloaded afterward
+    unsafe {
+        asm!("sti", "hlt", options(nostack));
+    }
+}
+
+/// Counts the CPU cycles since reset, at a constant rate on modern CPUs
+pub(crate) fn read_timestamp_counter() -> u64 {
+    // Safe : reading the timestamp counter has no side effect
+    unsafe { core::arch::x86_64::_rdtsc() }
+}
+
+/// Stops the CPU for good : interrupts are disabled, so only a non-maskable interrupt may wake it up
+pub(crate) fn halt_forever() -> ! {
+    loop {
+        // Safe : halting has no memory effect
+        unsafe {
+            asm!("cli", "hlt", options(nomem, nostack));
+        }
+    }
+}
+
+/// Runs `f` on the stack whose top is `stack_top`, never coming back to the current stack : if `f` returns,
+/// the CPU halts
+///
+/// `f` is moved to the top of the new stack first, so that nothing refers to the current stack afterwards, which
+/// may then be freed
+///
+/// # Safety
+/// `stack_top` must be the 16-byte aligned top of a mapped stack that nothing else uses
+pub(crate) unsafe fn switch_stack<F: FnOnce()>(stack_top: u64, f: F) -> ! {
+    extern "sysv64" fn call<F: FnOnce()>(f: *mut F) -> ! {
+        // Safe : `f` was moved above the frames of this call, on the new stack, and is only read once
+        let f = unsafe { f.read() };
+        f();
+        halt_forever()
+    }
+    let alignment = mem::align_of::<F>().max(16) as u64;
+    let f_address = (stack_top - mem::size_of::<F>() as u64) & !(alignment - 1);
+    ptr::write(f_address as *mut F, f);
+    asm!(
+        "mov rsp, {f_address}",
+        "call {call}",
+        f_address = in(reg) f_address,
+        call = sym call::<F>,
+        in("rdi") f_address,
+        options(noreturn),
+    );
+}
@@ -0,0 +1,24 @@
+use core::arch::asm;
+
+/// # Safety
+/// Reading `port` mustn't break any device state the kernel relies on
+pub(crate) unsafe fn read_u8(port: u16) -> u8 {
+    let value: u8;
+    asm!("in al, dx", in("dx") port, out("al") value, options(nomem, nostack, preserves_flags));
+    value
+}
+
+/// # Safety
+/// Writing `value` into `port` mustn't break any device state the kernel relies on
+pub(crate) unsafe fn write_u8(port: u16, value: u8) {
+    asm!("out dx, al", in("dx") port, in("al") value, options(nomem, nostack, preserves_flags));
+}
+
+/// Gives slow legacy devices some time between two accesses, by writing into an unused port
+pub(crate) fn wait_a_bit() {
+    const UNUSED_PORT: u16 = 0x80;
+    // Safe : the POST code port is unused once booted
+    unsafe {
+        write_u8(UNUSED_PORT, 0);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::kernel::console;
+use crate::kernel::console::DisposablePanicWriter;
+use crate::kernel::cpu;
+use crate::kernel::interrupts::InterruptFrame;
+use core::fmt::{Display, Formatter, Write};
+
+pub(super) const NON_MASKABLE_INTERRUPT_VECTOR: u64 = 2;
+const BREAKPOINT_VECTOR: u64 = 3;
+pub(super) const DOUBLE_FAULT_VECTOR: u64 = 8;
+const PAGE_FAULT_VECTOR: u64 = 14;
+pub(super) const MACHINE_CHECK_VECTOR: u64 = 18;
+
+/// Name and mnemonic of the 32 exceptions, indexed by vector
+const EXCEPTION_NAMES: [(&str, &str); 32] = [
+    ("Division error", "#DE"),
+    ("Debug", "#DB"),
+    ("Non-maskable interrupt", "NMI"),
+    ("Breakpoint", "#BP"),
+    ("Overflow", "#OF"),
+    ("Bound range exceeded", "#BR"),
+    ("Invalid opcode", "#UD"),
+    ("Device not available", "#NM"),
+    ("Double fault", "#DF"),
+    ("Coprocessor segment overrun", "-"),
+    ("Invalid TSS", "#TS"),
+    ("Segment not present", "#NP"),
+    ("Stack-segment fault", "#SS"),
+    ("General protection fault", "#GP"),
+    ("Page fault", "#PF"),
+    ("Reserved", "-"),
+    ("x87 floating-point exception", "#MF"),
+    ("Alignment check", "#AC"),
+    ("Machine check", "#MC"),
+    ("SIMD floating-point exception", "#XM"),
+    ("Virtualization exception", "#VE"),
+    ("Control protection exception", "#CP"),
+    ("Reserved", "-"),
+    ("Reserved", "-"),
+    ("Reserved", "-"),
+    ("Reserved", "-"),
+    ("Reserved", "-"),
+    ("Reserved", "-"),
+    ("Hypervisor injection exception", "#HV"),
+    ("VMM communication exception", "#VC"),
+    ("Security exception", "#SX"),
+    ("Reserved", "-"),
+];
+
+struct ExceptionReport<'a> {
+    frame: &'a InterruptFrame,
+    cr2: u64,
+    cr3: u64,
+}
+
+impl Display for ExceptionReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let frame = self.frame;
+        let (name, mnemonic) = EXCEPTION_NAMES[frame.vector as usize];
+        writeln!(
+            f,
+            "CPU exception {} : {} ({})",
+            frame.vector, name, mnemonic
+        )?;
+        writeln!(f, "error code : {:#x}", frame.error_code)?;
+        if frame.vector == PAGE_FAULT_VECTOR {
+            writeln!(f, "{}", PageFaultCause(frame.error_code))?;
+        }
+        writeln!(
+            f,
+            "RIP {:#018x}  CS  {:#06x}  RFLAGS {:#018x}",
+            frame.rip, frame.cs, frame.rflags
+        )?;
+        writeln!(
+            f,
+            "RSP {:#018x}  SS  {:#06x}  CR2 {:#018x}  CR3 {:#018x}",
+            frame.rsp, frame.ss, self.cr2, self.cr3
+        )?;
+        writeln!(
+            f,
+            "RAX {:#018x}  RBX {:#018x}  RCX {:#018x}  RDX {:#018x}",
+            frame.rax, frame.rbx, frame.rcx, frame.rdx
+        )?;
+        writeln!(
+            f,
+            "RSI {:#018x}  RDI {:#018x}  RBP {:#018x}",
+            frame.rsi, frame.rdi, frame.rbp
+        )?;
+        writeln!(
+            f,
+            "R8  {:#018x}  R9  {:#018x}  R10 {:#018x}  R11 {:#018x}",
+            frame.r8, frame.r9, frame.r10, frame.r11
+        )?;
+        write!(
+            f,
+            "R12 {:#018x}  R13 {:#018x}  R14 {:#018x}  R15 {:#018x}",
+            frame.r12, frame.r13, frame.r14, frame.r15
+        )
+    }
+}
+
+/// Decodes the error code of a page fault
+struct PageFaultCause(u64);
+
+impl Display for PageFaultCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let present = if self.0 & 1 != 0 {
+            "protection violation"
+        } else {
+            "page not present"
+        };
+        let access = if self.0 & (1 << 4) != 0 {
+            "instruction fetch"
+        } else if self.0 & (1 << 1) != 0 {
+            "write"
+        } else {
+            "read"
+        };
+        let mode = if self.0 & (1 << 2) != 0 {
+            "user"
+        } else {
+            "kernel"
+        };
+        write!(f, "{} on {} access in {} mode", present, access, mode)
+    }
+}
+
+/// Reports the exception through the console, then halts, except for breakpoints which resume
+pub(super) fn handle_exception(frame: &mut InterruptFrame) {
+    let report = ExceptionReport {
+        frame,
+        cr2: cpu::read_cr2(),
+        cr3: cpu::read_cr3(),
+    };
+    if frame.vector == BREAKPOINT_VECTOR {
+        // The console keeps its usual colors, as the code resumes
+        if let Some(mut console) = console::try_lock() {
+            let _ = writeln!(console, "\n{}", report);
+        }
+        return;
+    }
+    // Safe : like the panic handler, the faulting code is never resumed
+    if let Some(mut console) = unsafe { console::force_lock() } {
+        DisposablePanicWriter::new(&mut console).report(&report);
+    }
+    cpu::halt_forever();
+}
@@ -0,0 +1,81 @@
+use crate::kernel::acpi::madt::{IoApicInfo, IsaIrqRoute};
+use crate::kernel::memory::paging::{with_kernel_address_space, PageFlags};
+
+const REGISTER_SELECT: u64 = 0x00;
+const REGISTER_WINDOW: u64 = 0x10;
+
+const VERSION_REGISTER: u32 = 0x01;
+const FIRST_REDIRECTION_REGISTER: u32 = 0x10;
+
+const ACTIVE_LOW: u32 = 1 << 13;
+const LEVEL_TRIGGERED: u32 = 1 << 15;
+const MASKED: u32 = 1 << 16;
+
+#[derive(Debug)]
+pub(super) struct IoApic {
+    /// Identity mapped address of the registers
+    address: u64,
+    gsi_base: u32,
+    redirection_entry_count: u32,
+}
+
+impl IoApic {
+    /// Maps the registers uncached and masks every interrupt
+    ///
+    /// # Panics
+    /// Panics if the registers can't be mapped
+    pub(super) fn new(info: IoApicInfo) -> Self {
+        with_kernel_address_space(|address_space| {
+            address_space.protect_page(info.address, PageFlags::KERNEL_MMIO)
+        })
+        .expect("cannot map the IO APIC registers");
+        let mut io_apic = Self {
+            address: info.address,
+            gsi_base: info.gsi_base,
+            redirection_entry_count: 0,
+        };
+        // Safe : the registers were just mapped
+        unsafe {
+            io_apic.redirection_entry_count = (io_apic.read(VERSION_REGISTER) >> 16 & 0xFF) + 1;
+            for index in 0..io_apic.redirection_entry_count {
+                io_apic.write(FIRST_REDIRECTION_REGISTER + index * 2, MASKED);
+            }
+        }
+        io_apic
+    }
+
+    /// # Safety
+    /// `register` must be an existing register
+    unsafe fn read(&self, register: u32) -> u32 {
+        ((self.address + REGISTER_SELECT) as *mut u32).write_volatile(register);
+        ((self.address + REGISTER_WINDOW) as *const u32).read_volatile()
+    }
+    /// # Safety
+    /// `register` must be an existing register, and `value` mustn't break any interrupt routing
+    unsafe fn write(&mut self, register: u32, value: u32) {
+        ((self.address + REGISTER_SELECT) as *mut u32).write_volatile(register);
+        ((self.address + REGISTER_WINDOW) as *mut u32).write_volatile(value);
+    }
+
+    pub(super) fn handles(&self, gsi: u32) -> bool {
+        gsi >= self.gsi_base && gsi < self.gsi_base + self.redirection_entry_count
+    }
+
+    /// Delivers the global system interrupt of `route` as `vector` to the local APIC whose ID is `apic_id`
+    pub(super) fn route(&mut self, route: IsaIrqRoute, vector: u8, apic_id: u8) {
+        let register = FIRST_REDIRECTION_REGISTER + (route.gsi - self.gsi_base) * 2;
+        let mut low = vector as u32;
+        if route.is_active_low {
+            low |= ACTIVE_LOW;
+        }
+        if route.is_level_triggered {
+            low |= LEVEL_TRIGGERED;
+        }
+        // Safe : the entry exists as `route.gsi` is handled by this IO APIC, and is masked while being changed
+        unsafe {
+            self.write(register, MASKED);
+            self.write(register + 1, (apic_id as u32) << 24);
+            self.write(register, low);
+        }
+    }
+}
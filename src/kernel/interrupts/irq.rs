@@ -0,0 +1,104 @@
+use crate::kernel::acpi::madt::{IsaIrqRoute, Madt};
+use crate::kernel::interrupts::io_apic::IoApic;
+use crate::kernel::interrupts::local_apic;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use spin::Mutex;
+
+/// Global system interrupt `n` is delivered as vector `FIRST_GSI_VECTOR + n`
+pub(super) const FIRST_GSI_VECTOR: u64 = 32;
+const GSI_COUNT: u64 = 24;
+pub(crate) const LOCAL_APIC_TIMER_VECTOR: u64 = FIRST_GSI_VECTOR + GSI_COUNT;
+/// The low 4 bits of the spurious vector must be set on older local APICs
+pub(super) const SPURIOUS_VECTOR: u64 = 63;
+pub(super) const LAST_VECTOR: u64 = SPURIOUS_VECTOR;
+
+const HANDLER_COUNT: usize = (LAST_VECTOR - FIRST_GSI_VECTOR + 1) as usize;
+const NO_HANDLER: usize = 0;
+
+/// `fn()` handlers stored as addresses, so that interrupts never wait for a lock to find them
+static HANDLERS: [AtomicUsize; HANDLER_COUNT] =
+    [const { AtomicUsize::new(NO_HANDLER) }; HANDLER_COUNT];
+
+struct InterruptControllers {
+    io_apics: Vec<IoApic>,
+    isa_irq_routes: [IsaIrqRoute; 16],
+}
+
+static INTERRUPT_CONTROLLERS: Mutex<Option<InterruptControllers>> = Mutex::new(None);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum IrqError {
+    VectorAlreadyUsed,
+    /// No IO APIC handles the global system interrupt the IRQ is wired to
+    NotWired,
+}
+
+/// Must be called once, after the local APIC is enabled
+pub(super) fn init(madt: &Madt) {
+    let io_apics = madt
+        .io_apics
+        .iter()
+        .map(|info| IoApic::new(*info))
+        .collect();
+    *INTERRUPT_CONTROLLERS.lock() = Some(InterruptControllers {
+        io_apics,
+        isa_irq_routes: madt.isa_irq_routes,
+    });
+}
+
+/// Runs `handler` for every interrupt delivered as `vector`, which must be at least `FIRST_GSI_VECTOR`
+///
+/// The handler runs with interrupts disabled, and must not take any lock that the interrupted code may hold
+pub(crate) fn register_vector_handler(vector: u64, handler: fn()) -> Result<(), IrqError> {
+    HANDLERS[(vector - FIRST_GSI_VECTOR) as usize]
+        .compare_exchange(
+            NO_HANDLER,
+            handler as usize,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        )
+        .map(|_| ())
+        .map_err(|_| IrqError::VectorAlreadyUsed)
+}
+
+/// Runs `handler` for every occurrence of the legacy ISA IRQ `isa_irq`, and unmasks it
+///
+/// The handler runs with interrupts disabled, and must not take any lock that the interrupted code may hold
+///
+/// # Panics
+/// Panics if the interrupt controllers aren't initialized
+pub(crate) fn register_isa_irq_handler(isa_irq: u8, handler: fn()) -> Result<(), IrqError> {
+    let mut controllers = INTERRUPT_CONTROLLERS.lock();
+    let controllers = controllers
+        .as_mut()
+        .expect("the interrupt controllers must be initialized first");
+    let route = controllers.isa_irq_routes[isa_irq as usize];
+    if route.gsi as u64 >= GSI_COUNT {
+        return Err(IrqError::NotWired);
+    }
+    let io_apic = controllers
+        .io_apics
+        .iter_mut()
+        .find(|io_apic| io_apic.handles(route.gsi))
+        .ok_or(IrqError::NotWired)?;
+    let vector = FIRST_GSI_VECTOR + route.gsi as u64;
+    register_vector_handler(vector, handler)?;
+    io_apic.route(route, vector as u8, local_apic::get_id());
+    Ok(())
+}
+
+/// Runs the handler of `vector`, then signals the end of the interrupt
+pub(super) fn handle_irq(vector: u64) {
+    // Spurious interrupts aren't real interrupts, and must not be acknowledged
+    if vector == SPURIOUS_VECTOR {
+        return;
+    }
+    let handler = HANDLERS[(vector - FIRST_GSI_VECTOR) as usize].load(Ordering::Acquire);
+    if handler != NO_HANDLER {
+        // Safe : only `fn()` addresses are stored
+        let handler: fn() = unsafe { core::mem::transmute(handler) };
+        handler();
+    }
+    local_apic::end_of_interrupt();
+}
@@ -0,0 +1,104 @@
+use crate::kernel::cpu;
+use crate::kernel::memory::paging::{with_kernel_address_space, PageFlags};
+use crate::kernel::time;
+use core::hint;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+
+const APIC_BASE_MSR: u32 = 0x1B;
+const APIC_BASE_ENABLE: u64 = 1 << 11;
+
+const ID_REGISTER: u64 = 0x20;
+const TASK_PRIORITY_REGISTER: u64 = 0x80;
+const END_OF_INTERRUPT_REGISTER: u64 = 0xB0;
+const SPURIOUS_INTERRUPT_VECTOR_REGISTER: u64 = 0xF0;
+const SOFTWARE_ENABLE: u32 = 1 << 8;
+const TIMER_REGISTER: u64 = 0x320;
+const TIMER_INITIAL_COUNT_REGISTER: u64 = 0x380;
+const TIMER_CURRENT_COUNT_REGISTER: u64 = 0x390;
+const TIMER_DIVIDE_CONFIGURATION_REGISTER: u64 = 0x3E0;
+const TIMER_MASKED: u32 = 1 << 16;
+const TIMER_PERIODIC: u32 = 1 << 17;
+/// The bus clock divided by 16
+const TIMER_DIVIDE_BY_16: u32 = 0b0011;
+/// Long enough for a precise count, short enough not to slow the boot down
+const TIMER_CALIBRATION_DURATION: Duration = Duration::from_millis(10);
+
+/// Identity mapped address of the local APIC registers, null until initialized
+static LOCAL_APIC_ADDRESS: AtomicU64 = AtomicU64::new(0);
+
+/// # Safety
+/// The local APIC must be initialized, and `offset` must be the offset of an existing register
+unsafe fn read_register(offset: u64) -> u32 {
+    ((LOCAL_APIC_ADDRESS.load(Ordering::Relaxed) + offset) as *const u32).read_volatile()
+}
+
+/// # Safety
+/// The local APIC must be initialized, and `offset` must be the offset of an existing register
+unsafe fn write_register(offset: u64, value: u32) {
+    ((LOCAL_APIC_ADDRESS.load(Ordering::Relaxed) + offset) as *mut u32).write_volatile(value);
+}
+
+/// Enables the local APIC of the running CPU, accepting every interrupt priority
+///
+/// # Panics
+/// Panics if the registers can't be mapped uncached
+pub(super) fn init(address: u64, spurious_vector: u8) {
+    with_kernel_address_space(|address_space| {
+        address_space.protect_page(address, PageFlags::KERNEL_MMIO)
+    })
+    .expect("cannot map the local APIC registers");
+    LOCAL_APIC_ADDRESS.store(address, Ordering::Relaxed);
+    // Safe :
+    // - The APIC base MSR exists on every x86_64 CPU
+    // - The registers were just mapped, and the APIC isn't used before being enabled here
+    unsafe {
+        cpu::write_msr(
+            APIC_BASE_MSR,
+            cpu::read_msr(APIC_BASE_MSR) | APIC_BASE_ENABLE,
+        );
+        write_register(TASK_PRIORITY_REGISTER, 0);
+        write_register(
+            SPURIOUS_INTERRUPT_VECTOR_REGISTER,
+            SOFTWARE_ENABLE | spurious_vector as u32,
+        );
+    }
+}
+
+pub(crate) fn get_id() -> u8 {
+    // Safe : reading the ID has no side effect
+    unsafe { (read_register(ID_REGISTER) >> 24) as u8 }
+}
+
+/// Signals the end of the interrupt being handled, so that the next ones may be delivered
+pub(crate) fn end_of_interrupt() {
+    // Safe : the EOI register only accepts 0
+    unsafe {
+        write_register(END_OF_INTERRUPT_REGISTER, 0);
+    }
+}
+
+/// Raises `vector` every `period`, the timer frequency being measured against the uptime clock first
+///
+/// Returns the timer frequency, in ticks per second
+pub(super) fn start_periodic_timer(vector: u8, period: Duration) -> u64 {
+    // Safe : the timer registers exist on every local APIC, and the timer is masked while calibrated
+    let calibration_tick_count = unsafe {
+        write_register(TIMER_DIVIDE_CONFIGURATION_REGISTER, TIMER_DIVIDE_BY_16);
+        write_register(TIMER_REGISTER, TIMER_MASKED);
+        let start = time::get_uptime();
+        write_register(TIMER_INITIAL_COUNT_REGISTER, u32::MAX);
+        while time::get_uptime() - start < TIMER_CALIBRATION_DURATION {
+            hint::spin_loop();
+        }
+        u32::MAX - read_register(TIMER_CURRENT_COUNT_REGISTER)
+    };
+    let frequency = calibration_tick_count as u128 * 1000 / TIMER_CALIBRATION_DURATION.as_millis();
+    let period_tick_count = (frequency * period.as_millis() / 1000).clamp(1, u32::MAX as u128);
+    // Safe : the handler of `vector` is registered by the caller
+    unsafe {
+        write_register(TIMER_REGISTER, TIMER_PERIODIC | vector as u32);
+        write_register(TIMER_INITIAL_COUNT_REGISTER, period_tick_count as u32);
+    }
+    frequency as u64
+}
@@ -0,0 +1,219 @@
+use crate::kernel::acpi::madt::Madt;
+use crate::kernel::cpu;
+use crate::kernel::cpu::gdt;
+use crate::kernel::cpu::gdt::DescriptorTablePointer;
+use core::arch::{asm, global_asm};
+use core::mem;
+use core::ptr::addr_of;
+use core::time::Duration;
+
+mod exceptions;
+mod io_apic;
+pub(crate) mod irq;
+mod local_apic;
+mod pic;
+
+const IDT_ENTRY_COUNT: usize = 256;
+const EXCEPTION_COUNT: u64 = 32;
+/// Every interrupt stub starts on this alignment, so that stub `n` is at `interrupt_stubs + n * INTERRUPT_STUB_SIZE`
+const INTERRUPT_STUB_SIZE: u64 = 16;
+
+// Every stub pushes a null error code when the CPU doesn't push one, then its vector, so that
+// all interrupts end up in `interrupt_common_stub` with the same stack layout : an `InterruptFrame`.
+// The SSE state is saved too, as Rust code may use the SSE registers.
+global_asm!(
+    ".balign 16",
+    "interrupt_stubs:",
+    ".irp vector, 0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46,47,48,49,50,51,52,53,54,55,56,57,58,59,60,61,62,63",
+    ".balign 16",
+    ".if \\vector != 8 && \\vector != 10 && \\vector != 11 && \\vector != 12 && \\vector != 13 && \\vector != 14 && \\vector != 17 && \\vector != 21 && \\vector != 29 && \\vector != 30",
+    "push 0",
+    ".endif",
+    "push \\vector",
+    "jmp interrupt_common_stub",
+    ".endr",
+    "interrupt_common_stub:",
+    "push rax",
+    "push rbx",
+    "push rcx",
+    "push rdx",
+    "push rsi",
+    "push rdi",
+    "push rbp",
+    "push r8",
+    "push r9",
+    "push r10",
+    "push r11",
+    "push r12",
+    "push r13",
+    "push r14",
+    "push r15",
+    "mov rdi, rsp",
+    "sub rsp, 512",
+    "fxsave64 [rsp]",
+    "cld",
+    "call {handle_interrupt}",
+    "fxrstor64 [rsp]",
+    "add rsp, 512",
+    "pop r15",
+    "pop r14",
+    "pop r13",
+    "pop r12",
+    "pop r11",
+    "pop r10",
+    "pop r9",
+    "pop r8",
+    "pop rbp",
+    "pop rdi",
+    "pop rsi",
+    "pop rdx",
+    "pop rcx",
+    "pop rbx",
+    "pop rax",
+    // vector and error code
+    "add rsp, 16",
+    "iretq",
+    handle_interrupt = sym handle_interrupt,
+);
+
+extern "C" {
+    fn interrupt_stubs();
+}
+
+/// The stack content when an interrupt stub calls `handle_interrupt`, from the last pushed value
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct InterruptFrame {
+    pub(crate) r15: u64,
+    pub(crate) r14: u64,
+    pub(crate) r13: u64,
+    pub(crate) r12: u64,
+    pub(crate) r11: u64,
+    pub(crate) r10: u64,
+    pub(crate) r9: u64,
+    pub(crate) r8: u64,
+    pub(crate) rbp: u64,
+    pub(crate) rdi: u64,
+    pub(crate) rsi: u64,
+    pub(crate) rdx: u64,
+    pub(crate) rcx: u64,
+    pub(crate) rbx: u64,
+    pub(crate) rax: u64,
+    pub(crate) vector: u64,
+    pub(crate) error_code: u64,
+    pub(crate) rip: u64,
+    pub(crate) cs: u64,
+    pub(crate) rflags: u64,
+    pub(crate) rsp: u64,
+    pub(crate) ss: u64,
+}
+
+extern "sysv64" fn handle_interrupt(frame: &mut InterruptFrame) {
+    if frame.vector < EXCEPTION_COUNT {
+        exceptions::handle_exception(frame);
+    } else {
+        irq::handle_irq(frame.vector);
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct IdtEntry {
+    offset_low: u16,
+    selector: u16,
+    ist_index: u8,
+    attributes: u8,
+    offset_middle: u16,
+    offset_high: u32,
+    reserved: u32,
+}
+
+impl IdtEntry {
+    const MISSING: Self = Self {
+        offset_low: 0,
+        selector: 0,
+        ist_index: 0,
+        attributes: 0,
+        offset_middle: 0,
+        offset_high: 0,
+        reserved: 0,
+    };
+    /// Present, ring 0, 64-bit interrupt gate : interrupts are disabled while the handler runs
+    const INTERRUPT_GATE_ATTRIBUTES: u8 = 0x8E;
+
+    fn new(handler_address: u64, ist_index: u8) -> Self {
+        Self {
+            offset_low: handler_address as u16,
+            selector: gdt::KERNEL_CODE_SELECTOR,
+            ist_index,
+            attributes: Self::INTERRUPT_GATE_ATTRIBUTES,
+            offset_middle: (handler_address >> 16) as u16,
+            offset_high: (handler_address >> 32) as u32,
+            reserved: 0,
+        }
+    }
+}
+
+static mut IDT: [IdtEntry; IDT_ENTRY_COUNT] = [IdtEntry::MISSING; IDT_ENTRY_COUNT];
+
+fn get_ist_index(vector: u64) -> u8 {
+    match vector {
+        exceptions::DOUBLE_FAULT_VECTOR => gdt::DOUBLE_FAULT_IST_INDEX,
+        exceptions::NON_MASKABLE_INTERRUPT_VECTOR => gdt::NON_MASKABLE_INTERRUPT_IST_INDEX,
+        exceptions::MACHINE_CHECK_VECTOR => gdt::MACHINE_CHECK_IST_INDEX,
+        _ => 0,
+    }
+}
+
+/// Loads an IDT routing every CPU exception to a handler that reports it through the console,
+/// and every IRQ to `irq::handle_irq`
+///
+/// Interrupts stay disabled until `init_controllers`.
+/// Must be called once, after `gdt::init`
+pub(crate) fn init() {
+    cpu::disable_interrupts();
+    let stubs_address = interrupt_stubs as *const () as u64;
+    // Safe :
+    // - This is called once, before interrupts are enabled, so nothing else accesses the IDT
+    // - Every stub exists, and saves and restores everything it changes
+    unsafe {
+        for vector in 0..=irq::LAST_VECTOR {
+            IDT[vector as usize] = IdtEntry::new(
+                stubs_address + vector * INTERRUPT_STUB_SIZE,
+                get_ist_index(vector),
+            );
+        }
+        let idt_pointer = DescriptorTablePointer {
+            limit: (mem::size_of::<[IdtEntry; IDT_ENTRY_COUNT]>() - 1) as u16,
+            base: addr_of!(IDT) as u64,
+        };
+        asm!("lidt [{}]", in(reg) &idt_pointer, options(readonly, nostack, preserves_flags));
+    }
+}
+
+/// Disables the legacy PICs, enables the local APIC and masks every IO APIC interrupt, then enables interrupts
+///
+/// Must be called once, after `init`
+///
+/// # Panics
+/// Panics if the controller registers can't be mapped
+pub(crate) fn init_controllers(madt: &Madt) {
+    if madt.has_legacy_pics {
+        pic::remap_and_disable();
+    }
+    local_apic::init(madt.local_apic_address, irq::SPURIOUS_VECTOR as u8);
+    irq::init(madt);
+    cpu::enable_interrupts();
+}
+
+/// Runs `handler` every `period`, from the local APIC timer
+///
+/// Returns the timer frequency, in ticks per second.
+/// Must be called once, after `init_controllers`
+pub(crate) fn start_timer(period: Duration, handler: fn()) -> Result<u64, irq::IrqError> {
+    irq::register_vector_handler(irq::LOCAL_APIC_TIMER_VECTOR, handler)?;
+    Ok(local_apic::start_periodic_timer(
+        irq::LOCAL_APIC_TIMER_VECTOR as u8,
+        period,
+    ))
+}
@@ -0,0 +1,37 @@
+use crate::kernel::cpu::port;
+
+const PIC_1_COMMAND_PORT: u16 = 0x20;
+const PIC_1_DATA_PORT: u16 = 0x21;
+const PIC_2_COMMAND_PORT: u16 = 0xA0;
+const PIC_2_DATA_PORT: u16 = 0xA1;
+
+const INITIALIZE_COMMAND: u8 = 0x11;
+const MODE_8086: u8 = 0x01;
+/// The IRQs of both PICs are moved right after the CPU exceptions, which they would overlap otherwise
+const PIC_1_VECTOR_OFFSET: u8 = 0x20;
+const PIC_2_VECTOR_OFFSET: u8 = 0x28;
+const MASK_ALL: u8 = 0xFF;
+
+/// Remaps the legacy 8259 PICs, then masks all their IRQs, as the IO APICs deliver them instead
+pub(super) fn remap_and_disable() {
+    // Safe : the PICs are only programmed here, before interrupts are enabled
+    unsafe {
+        let writes = [
+            (PIC_1_COMMAND_PORT, INITIALIZE_COMMAND),
+            (PIC_2_COMMAND_PORT, INITIALIZE_COMMAND),
+            (PIC_1_DATA_PORT, PIC_1_VECTOR_OFFSET),
+            (PIC_2_DATA_PORT, PIC_2_VECTOR_OFFSET),
+            // PIC 2 is cascaded on IRQ 2 of PIC 1
+            (PIC_1_DATA_PORT, 1 << 2),
+            (PIC_2_DATA_PORT, 2),
+            (PIC_1_DATA_PORT, MODE_8086),
+            (PIC_2_DATA_PORT, MODE_8086),
+            (PIC_1_DATA_PORT, MASK_ALL),
+            (PIC_2_DATA_PORT, MASK_ALL),
+        ];
+        for (port, value) in writes {
+            port::write_u8(port, value);
+            port::wait_a_bit();
+        }
+    }
+}
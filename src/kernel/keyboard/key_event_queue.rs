@@ -0,0 +1,58 @@
+use crate::kernel::keyboard::KeyEvent;
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+const CAPACITY: usize = 128;
+
+/// A lock-free ring buffer with one producer, the keyboard IRQ handler, and one consumer
+///
+/// The indices only grow, wrapping around `usize`, so that a full queue can be told apart from an empty one
+pub(super) struct KeyEventQueue {
+    events: UnsafeCell<[MaybeUninit<KeyEvent>; CAPACITY]>,
+    /// Index of the next event to pop, only written by the consumer
+    read_index: AtomicUsize,
+    /// Index of the next event to push, only written by the producer
+    write_index: AtomicUsize,
+}
+
+// Safe : a slot is only written by the producer while the consumer can't read it, and conversely
+unsafe impl Sync for KeyEventQueue {}
+
+impl KeyEventQueue {
+    pub(super) const fn new() -> Self {
+        Self {
+            events: UnsafeCell::new([MaybeUninit::uninit(); CAPACITY]),
+            read_index: AtomicUsize::new(0),
+            write_index: AtomicUsize::new(0),
+        }
+    }
+
+    /// Drops the event and returns false if the queue is full
+    ///
+    /// # Safety
+    /// Must not be called by more than one producer at a time
+    pub(super) unsafe fn push(&self, event: KeyEvent) -> bool {
+        let write_index = self.write_index.load(Ordering::Relaxed);
+        if write_index.wrapping_sub(self.read_index.load(Ordering::Acquire)) == CAPACITY {
+            return false;
+        }
+        (*self.events.get())[write_index % CAPACITY].write(event);
+        self.write_index
+            .store(write_index.wrapping_add(1), Ordering::Release);
+        true
+    }
+
+    /// # Safety
+    /// Must not be called by more than one consumer at a time
+    pub(super) unsafe fn pop(&self) -> Option<KeyEvent> {
+        let read_index = self.read_index.load(Ordering::Relaxed);
+        if read_index == self.write_index.load(Ordering::Acquire) {
+            return None;
+        }
+        let event = (*self.events.get())[read_index % CAPACITY].assume_init();
+        self.read_index
+            .store(read_index.wrapping_add(1), Ordering::Release);
+        Some(event)
+    }
+}
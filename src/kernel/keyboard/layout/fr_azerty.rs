@@ -0,0 +1,65 @@
+use crate::kernel::keyboard::layout::{DeadKey, KeyMapping, Layout};
+use crate::kernel::keyboard::KeyCode::*;
+
+/// The French AZERTY layout, where ^ and ¨ are dead keys typing â, ê, ë, ï...
+pub(super) const LAYOUT: Layout = Layout {
+    name: "fr",
+    keys: &[
+        KeyMapping::new(Backquote, '²', '²'),
+        KeyMapping::new(Digit1, '&', '1'),
+        KeyMapping::new(Digit2, 'é', '2')
+            .with_alt_gr('~')
+            .caps_lockable(),
+        KeyMapping::new(Digit3, '"', '3').with_alt_gr('#'),
+        KeyMapping::new(Digit4, '\'', '4').with_alt_gr('{'),
+        KeyMapping::new(Digit5, '(', '5').with_alt_gr('['),
+        KeyMapping::new(Digit6, '-', '6').with_alt_gr('|'),
+        KeyMapping::new(Digit7, 'è', '7')
+            .with_alt_gr('`')
+            .caps_lockable(),
+        KeyMapping::new(Digit8, '_', '8').with_alt_gr('\\'),
+        KeyMapping::new(Digit9, 'ç', '9')
+            .with_alt_gr('^')
+            .caps_lockable(),
+        KeyMapping::new(Digit0, 'à', '0')
+            .with_alt_gr('@')
+            .caps_lockable(),
+        KeyMapping::new(Minus, ')', '°').with_alt_gr(']'),
+        KeyMapping::new(Equal, '=', '+').with_alt_gr('}'),
+        KeyMapping::letter(Q, 'a'),
+        KeyMapping::letter(W, 'z'),
+        KeyMapping::letter(E, 'e').with_alt_gr('€'),
+        KeyMapping::letter(R, 'r'),
+        KeyMapping::letter(T, 't'),
+        KeyMapping::letter(Y, 'y'),
+        KeyMapping::letter(U, 'u'),
+        KeyMapping::letter(I, 'i'),
+        KeyMapping::letter(O, 'o'),
+        KeyMapping::letter(P, 'p'),
+        KeyMapping::dead(LeftBracket, DeadKey::Circumflex, DeadKey::Diaeresis),
+        KeyMapping::new(RightBracket, '$', '£').with_alt_gr('¤'),
+        KeyMapping::letter(A, 'q'),
+        KeyMapping::letter(S, 's'),
+        KeyMapping::letter(D, 'd'),
+        KeyMapping::letter(F, 'f'),
+        KeyMapping::letter(G, 'g'),
+        KeyMapping::letter(H, 'h'),
+        KeyMapping::letter(J, 'j'),
+        KeyMapping::letter(K, 'k'),
+        KeyMapping::letter(L, 'l'),
+        KeyMapping::letter(Semicolon, 'm'),
+        KeyMapping::new(Quote, 'ù', '%').caps_lockable(),
+        KeyMapping::new(Backslash, '*', 'µ'),
+        KeyMapping::new(IntlBackslash, '<', '>'),
+        KeyMapping::letter(Z, 'w'),
+        KeyMapping::letter(X, 'x'),
+        KeyMapping::letter(C, 'c'),
+        KeyMapping::letter(V, 'v'),
+        KeyMapping::letter(B, 'b'),
+        KeyMapping::letter(N, 'n'),
+        KeyMapping::new(M, ',', '?'),
+        KeyMapping::new(Comma, ';', '.'),
+        KeyMapping::new(Period, ':', '/'),
+        KeyMapping::new(Slash, '!', '§'),
+    ],
+};
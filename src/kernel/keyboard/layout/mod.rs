@@ -0,0 +1,234 @@
+use crate::kernel::keyboard::{KeyCode, KeyEvent, Modifiers};
+
+mod fr_azerty;
+mod us_qwerty;
+
+pub(crate) const DEFAULT_LAYOUT: &Layout = &us_qwerty::LAYOUT;
+const LAYOUTS: [&Layout; 2] = [&us_qwerty::LAYOUT, &fr_azerty::LAYOUT];
+
+/// A key that types nothing by itself, but changes the next char typed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DeadKey {
+    Circumflex,
+    Diaeresis,
+}
+
+impl DeadKey {
+    /// The char typed when the next key doesn't combine with this one
+    const fn get_char(&self) -> char {
+        match self {
+            Self::Circumflex => '^',
+            Self::Diaeresis => '¨',
+        }
+    }
+    const fn combine(&self, c: char) -> Option<char> {
+        Some(match (self, c) {
+            (_, ' ') => self.get_char(),
+            (Self::Circumflex, 'a') => 'â',
+            (Self::Circumflex, 'e') => 'ê',
+            (Self::Circumflex, 'i') => 'î',
+            (Self::Circumflex, 'o') => 'ô',
+            (Self::Circumflex, 'u') => 'û',
+            (Self::Circumflex, 'A') => 'Â',
+            (Self::Circumflex, 'E') => 'Ê',
+            (Self::Circumflex, 'I') => 'Î',
+            (Self::Circumflex, 'O') => 'Ô',
+            (Self::Circumflex, 'U') => 'Û',
+            (Self::Diaeresis, 'a') => 'ä',
+            (Self::Diaeresis, 'e') => 'ë',
+            (Self::Diaeresis, 'i') => 'ï',
+            (Self::Diaeresis, 'o') => 'ö',
+            (Self::Diaeresis, 'u') => 'ü',
+            (Self::Diaeresis, 'y') => 'ÿ',
+            (Self::Diaeresis, 'A') => 'Ä',
+            (Self::Diaeresis, 'E') => 'Ë',
+            (Self::Diaeresis, 'I') => 'Ï',
+            (Self::Diaeresis, 'O') => 'Ö',
+            (Self::Diaeresis, 'U') => 'Ü',
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Symbol {
+    Char(char),
+    Dead(DeadKey),
+}
+
+/// What a key types, depending on the modifiers
+#[derive(Clone, Copy, Debug)]
+struct KeyMapping {
+    key_code: KeyCode,
+    normal: Option<Symbol>,
+    shifted: Option<Symbol>,
+    alt_gr: Option<Symbol>,
+    /// Caps lock types the upper case of `normal` instead, reverted by shift
+    is_caps_lockable: bool,
+}
+
+impl KeyMapping {
+    const fn new(key_code: KeyCode, normal: char, shifted: char) -> Self {
+        Self {
+            key_code,
+            normal: Some(Symbol::Char(normal)),
+            shifted: Some(Symbol::Char(shifted)),
+            alt_gr: None,
+            is_caps_lockable: false,
+        }
+    }
+    /// Shift and caps lock type the upper case letter
+    const fn letter(key_code: KeyCode, letter: char) -> Self {
+        Self {
+            key_code,
+            normal: Some(Symbol::Char(letter)),
+            shifted: Some(Symbol::Char(letter.to_ascii_uppercase())),
+            alt_gr: None,
+            is_caps_lockable: true,
+        }
+    }
+    const fn dead(key_code: KeyCode, normal: DeadKey, shifted: DeadKey) -> Self {
+        Self {
+            key_code,
+            normal: Some(Symbol::Dead(normal)),
+            shifted: Some(Symbol::Dead(shifted)),
+            alt_gr: None,
+            is_caps_lockable: false,
+        }
+    }
+    const fn with_alt_gr(mut self, alt_gr: char) -> Self {
+        self.alt_gr = Some(Symbol::Char(alt_gr));
+        self
+    }
+    const fn caps_lockable(mut self) -> Self {
+        self.is_caps_lockable = true;
+        self
+    }
+
+    fn get_symbol(&self, modifiers: Modifiers) -> Option<Symbol> {
+        if modifiers.contains(Modifiers::RIGHT_ALT) {
+            return self.alt_gr;
+        }
+        let is_shifted = if self.is_caps_lockable {
+            modifiers.is_shift() != modifiers.contains(Modifiers::CAPS_LOCK)
+        } else {
+            modifiers.is_shift()
+        };
+        if !is_shifted {
+            return self.normal;
+        }
+        match (self.is_caps_lockable, self.normal) {
+            // Non-letter keys like é type their own upper case under caps lock, but their shifted char under shift
+            (true, Some(Symbol::Char(c))) if !modifiers.is_shift() => {
+                Some(Symbol::Char(c.to_uppercase().next().unwrap_or(c)))
+            }
+            _ => self.shifted,
+        }
+    }
+}
+
+/// Maps the printing keys to the chars they type, the other keys being the same on every layout
+#[derive(Debug)]
+pub(crate) struct Layout {
+    /// Selects the layout at boot
+    pub(crate) name: &'static str,
+    keys: &'static [KeyMapping],
+}
+
+impl Layout {
+    pub(crate) fn find(name: &str) -> Option<&'static Self> {
+        LAYOUTS.into_iter().find(|layout| layout.name == name)
+    }
+
+    fn get_symbol(&self, key_code: KeyCode, modifiers: Modifiers) -> Option<Symbol> {
+        let is_num_lock = modifiers.contains(Modifiers::NUM_LOCK);
+        let keypad_char = |c| {
+            if is_num_lock {
+                Some(Symbol::Char(c))
+            } else {
+                None
+            }
+        };
+        let c = match key_code {
+            KeyCode::Space => ' ',
+            KeyCode::Tab => '\t',
+            KeyCode::Enter | KeyCode::KeypadEnter => '\n',
+            KeyCode::Backspace => '\u{8}',
+            KeyCode::KeypadDivide => '/',
+            KeyCode::KeypadMultiply => '*',
+            KeyCode::KeypadMinus => '-',
+            KeyCode::KeypadPlus => '+',
+            KeyCode::KeypadPeriod => return keypad_char('.'),
+            KeyCode::Keypad0 => return keypad_char('0'),
+            KeyCode::Keypad1 => return keypad_char('1'),
+            KeyCode::Keypad2 => return keypad_char('2'),
+            KeyCode::Keypad3 => return keypad_char('3'),
+            KeyCode::Keypad4 => return keypad_char('4'),
+            KeyCode::Keypad5 => return keypad_char('5'),
+            KeyCode::Keypad6 => return keypad_char('6'),
+            KeyCode::Keypad7 => return keypad_char('7'),
+            KeyCode::Keypad8 => return keypad_char('8'),
+            KeyCode::Keypad9 => return keypad_char('9'),
+            _ => {
+                return self
+                    .keys
+                    .iter()
+                    .find(|key| key.key_code == key_code)
+                    .and_then(|key| key.get_symbol(modifiers))
+            }
+        };
+        Some(Symbol::Char(c))
+    }
+}
+
+/// Turns key events into the chars they type through a layout, combining dead keys with the next char
+#[derive(Debug)]
+pub(crate) struct CharDecoder {
+    layout: &'static Layout,
+    dead_key: Option<DeadKey>,
+}
+
+impl CharDecoder {
+    pub(crate) const fn new(layout: &'static Layout) -> Self {
+        Self {
+            layout,
+            dead_key: None,
+        }
+    }
+
+    /// The char the key types without any modifier, like `a` for Ctrl+A, so that the shortcuts follow the layout
+    pub(crate) fn get_shortcut_char(&self, key_code: KeyCode) -> Option<char> {
+        match self.layout.get_symbol(key_code, Modifiers::NONE) {
+            Some(Symbol::Char(c)) => Some(c),
+            _ => None,
+        }
+    }
+    /// Returns up to two chars, as a dead key not combining with the next char types both
+    pub(crate) fn decode(&mut self, key_event: KeyEvent) -> impl Iterator<Item = char> {
+        let mut chars = (None, None);
+        let modifiers = key_event.modifiers;
+        // Ctrl and alt shortcuts type nothing, AltGr being the right alt only
+        if key_event.is_pressed && !modifiers.is_ctrl() && !modifiers.is_alt() {
+            match (
+                self.dead_key.take(),
+                self.layout.get_symbol(key_event.key_code, modifiers),
+            ) {
+                (None, Some(Symbol::Char(c))) => chars.0 = Some(c),
+                (None, Some(Symbol::Dead(dead_key))) => self.dead_key = Some(dead_key),
+                (Some(dead_key), Some(Symbol::Char(c))) => match dead_key.combine(c) {
+                    Some(combined_char) => chars.0 = Some(combined_char),
+                    None => chars = (Some(dead_key.get_char()), Some(c)),
+                },
+                (Some(dead_key), Some(Symbol::Dead(next_dead_key))) => {
+                    chars.0 = Some(dead_key.get_char());
+                    if next_dead_key != dead_key {
+                        self.dead_key = Some(next_dead_key);
+                    }
+                }
+                // Modifiers and other keys typing nothing keep the dead key waiting
+                (dead_key, None) => self.dead_key = dead_key,
+            }
+        }
+        chars.0.into_iter().chain(chars.1)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::kernel::keyboard::layout::{KeyMapping, Layout};
+use crate::kernel::keyboard::KeyCode::*;
+
+pub(super) const LAYOUT: Layout = Layout {
+    name: "us",
+    keys: &[
+        KeyMapping::new(Backquote, '`', '~'),
+        KeyMapping::new(Digit1, '1', '!'),
+        KeyMapping::new(Digit2, '2', '@'),
+        KeyMapping::new(Digit3, '3', '#'),
+        KeyMapping::new(Digit4, '4', '$'),
+        KeyMapping::new(Digit5, '5', '%'),
+        KeyMapping::new(Digit6, '6', '^'),
+        KeyMapping::new(Digit7, '7', '&'),
+        KeyMapping::new(Digit8, '8', '*'),
+        KeyMapping::new(Digit9, '9', '('),
+        KeyMapping::new(Digit0, '0', ')'),
+        KeyMapping::new(Minus, '-', '_'),
+        KeyMapping::new(Equal, '=', '+'),
+        KeyMapping::letter(Q, 'q'),
+        KeyMapping::letter(W, 'w'),
+        KeyMapping::letter(E, 'e'),
+        KeyMapping::letter(R, 'r'),
+        KeyMapping::letter(T, 't'),
+        KeyMapping::letter(Y, 'y'),
+        KeyMapping::letter(U, 'u'),
+        KeyMapping::letter(I, 'i'),
+        KeyMapping::letter(O, 'o'),
+        KeyMapping::letter(P, 'p'),
+        KeyMapping::new(LeftBracket, '[', '{'),
+        KeyMapping::new(RightBracket, ']', '}'),
+        KeyMapping::new(Backslash, '\\', '|'),
+        KeyMapping::letter(A, 'a'),
+        KeyMapping::letter(S, 's'),
+        KeyMapping::letter(D, 'd'),
+        KeyMapping::letter(F, 'f'),
+        KeyMapping::letter(G, 'g'),
+        KeyMapping::letter(H, 'h'),
+        KeyMapping::letter(J, 'j'),
+        KeyMapping::letter(K, 'k'),
+        KeyMapping::letter(L, 'l'),
+        KeyMapping::new(Semicolon, ';', ':'),
+        KeyMapping::new(Quote, '\'', '"'),
+        KeyMapping::new(IntlBackslash, '\\', '|'),
+        KeyMapping::letter(Z, 'z'),
+        KeyMapping::letter(X, 'x'),
+        KeyMapping::letter(C, 'c'),
+        KeyMapping::letter(V, 'v'),
+        KeyMapping::letter(B, 'b'),
+        KeyMapping::letter(N, 'n'),
+        KeyMapping::letter(M, 'm'),
+        KeyMapping::new(Comma, ',', '<'),
+        KeyMapping::new(Period, '.', '>'),
+        KeyMapping::new(Slash, '/', '?'),
+    ],
+};
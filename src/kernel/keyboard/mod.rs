@@ -0,0 +1,317 @@
+use crate::kernel::cpu;
+use crate::kernel::interrupts::irq;
+use crate::kernel::keyboard::key_event_queue::KeyEventQueue;
+use crate::kernel::keyboard::scancode::ScancodeDecoder;
+use core::ops::BitOr;
+use spin::Mutex;
+
+pub(crate) use layout::{CharDecoder, Layout, DEFAULT_LAYOUT};
+pub(crate) use ps2::Ps2Error;
+
+mod key_event_queue;
+mod layout;
+mod ps2;
+mod scancode;
+
+const KEYBOARD_ISA_IRQ: u8 = 1;
+
+/// A physical key, named after its US QWERTY label whatever the layout
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum KeyCode {
+    Escape,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    PrintScreen,
+    ScrollLock,
+    Pause,
+    Backquote,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    Digit0,
+    Minus,
+    Equal,
+    Backspace,
+    Tab,
+    Q,
+    W,
+    E,
+    R,
+    T,
+    Y,
+    U,
+    I,
+    O,
+    P,
+    LeftBracket,
+    RightBracket,
+    /// Above Enter on ANSI keyboards, left of it on ISO ones
+    Backslash,
+    CapsLock,
+    A,
+    S,
+    D,
+    F,
+    G,
+    H,
+    J,
+    K,
+    L,
+    Semicolon,
+    Quote,
+    Enter,
+    LeftShift,
+    /// The ISO key between left shift and Z
+    IntlBackslash,
+    Z,
+    X,
+    C,
+    V,
+    B,
+    N,
+    M,
+    Comma,
+    Period,
+    Slash,
+    RightShift,
+    LeftCtrl,
+    LeftSuper,
+    LeftAlt,
+    Space,
+    /// AltGr on most non-US layouts
+    RightAlt,
+    RightSuper,
+    Menu,
+    RightCtrl,
+    Insert,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    NumLock,
+    KeypadDivide,
+    KeypadMultiply,
+    KeypadMinus,
+    KeypadPlus,
+    KeypadEnter,
+    KeypadPeriod,
+    Keypad0,
+    Keypad1,
+    Keypad2,
+    Keypad3,
+    Keypad4,
+    Keypad5,
+    Keypad6,
+    Keypad7,
+    Keypad8,
+    Keypad9,
+}
+
+/// The modifier keys held and the locks enabled when a key event happened
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Modifiers(u16);
+
+impl Modifiers {
+    pub(crate) const NONE: Self = Self(0);
+    pub(crate) const LEFT_SHIFT: Self = Self(1);
+    pub(crate) const RIGHT_SHIFT: Self = Self(1 << 1);
+    pub(crate) const LEFT_CTRL: Self = Self(1 << 2);
+    pub(crate) const RIGHT_CTRL: Self = Self(1 << 3);
+    pub(crate) const LEFT_ALT: Self = Self(1 << 4);
+    pub(crate) const RIGHT_ALT: Self = Self(1 << 5);
+    pub(crate) const CAPS_LOCK: Self = Self(1 << 6);
+    pub(crate) const NUM_LOCK: Self = Self(1 << 7);
+    pub(crate) const SCROLL_LOCK: Self = Self(1 << 8);
+
+    pub(crate) const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    pub(crate) const fn intersects(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+    pub(crate) const fn without(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+    pub(crate) const fn toggle(&self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    pub(crate) const fn is_shift(&self) -> bool {
+        self.intersects(Self(Self::LEFT_SHIFT.0 | Self::RIGHT_SHIFT.0))
+    }
+    pub(crate) const fn is_ctrl(&self) -> bool {
+        self.intersects(Self(Self::LEFT_CTRL.0 | Self::RIGHT_CTRL.0))
+    }
+    pub(crate) const fn is_alt(&self) -> bool {
+        self.contains(Self::LEFT_ALT)
+    }
+
+    const fn get_held(key_code: KeyCode) -> Self {
+        match key_code {
+            KeyCode::LeftShift => Self::LEFT_SHIFT,
+            KeyCode::RightShift => Self::RIGHT_SHIFT,
+            KeyCode::LeftCtrl => Self::LEFT_CTRL,
+            KeyCode::RightCtrl => Self::RIGHT_CTRL,
+            KeyCode::LeftAlt => Self::LEFT_ALT,
+            KeyCode::RightAlt => Self::RIGHT_ALT,
+            _ => Self::NONE,
+        }
+    }
+    const fn get_lock(key_code: KeyCode) -> Self {
+        match key_code {
+            KeyCode::CapsLock => Self::CAPS_LOCK,
+            KeyCode::NumLock => Self::NUM_LOCK,
+            KeyCode::ScrollLock => Self::SCROLL_LOCK,
+            _ => Self::NONE,
+        }
+    }
+    /// The byte of the keyboard set LEDs command
+    const fn get_leds(&self) -> u8 {
+        let mut leds = 0;
+        if self.contains(Self::SCROLL_LOCK) {
+            leds |= 1;
+        }
+        if self.contains(Self::NUM_LOCK) {
+            leds |= 1 << 1;
+        }
+        if self.contains(Self::CAPS_LOCK) {
+            leds |= 1 << 2;
+        }
+        leds
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct KeyEvent {
+    pub(crate) key_code: KeyCode,
+    pub(crate) is_pressed: bool,
+    /// Including the key of this event if it's a modifier
+    pub(crate) modifiers: Modifiers,
+}
+
+/// The state of the keyboard, only used by its IRQ handler once initialized
+struct Keyboard {
+    decoder: ScancodeDecoder,
+    modifiers: Modifiers,
+    /// One bit per `KeyCode`, so that typematic repeats don't toggle the locks again
+    pressed_keys: u128,
+    /// LEDs to send once the keyboard acknowledges the set LEDs command
+    pending_leds: Option<u8>,
+}
+
+impl Keyboard {
+    fn handle_byte(&mut self, byte: u8) {
+        if byte == ps2::ACKNOWLEDGE {
+            if let Some(leds) = self.pending_leds.take() {
+                // Nothing better to do than keeping the LEDs wrong if the keyboard doesn't answer
+                let _ = ps2::write_data(leds);
+            }
+            return;
+        }
+        let Some((key_code, is_pressed)) = self.decoder.decode(byte) else {
+            return;
+        };
+        self.handle_key(key_code, is_pressed);
+        if key_code == KeyCode::Pause {
+            self.handle_key(key_code, false);
+        }
+    }
+
+    fn handle_key(&mut self, key_code: KeyCode, is_pressed: bool) {
+        let key_bit = 1 << key_code as u8;
+        let is_repeat = is_pressed && self.pressed_keys & key_bit != 0;
+        if is_pressed {
+            self.pressed_keys |= key_bit;
+            self.modifiers = self.modifiers | Modifiers::get_held(key_code);
+        } else {
+            self.pressed_keys &= !key_bit;
+            self.modifiers = self.modifiers.without(Modifiers::get_held(key_code));
+        }
+        let lock = Modifiers::get_lock(key_code);
+        if is_pressed && !is_repeat && lock != Modifiers::NONE {
+            self.modifiers = self.modifiers.toggle(lock);
+            self.pending_leds = Some(self.modifiers.get_leds());
+            let _ = ps2::write_data(ps2::SET_LEDS_COMMAND);
+        }
+        let event = KeyEvent {
+            key_code,
+            is_pressed,
+            modifiers: self.modifiers,
+        };
+        // Safe : the IRQ handler is the only producer
+        // Events are dropped while nothing reads them
+        let _ = unsafe { KEY_EVENTS.push(event) };
+    }
+}
+
+static KEYBOARD: Mutex<Option<Keyboard>> = Mutex::new(None);
+static KEY_EVENTS: KeyEventQueue = KeyEventQueue::new();
+
+fn handle_keyboard_irq() {
+    // The lock is only taken by this handler once the keyboard is initialized
+    let mut keyboard = KEYBOARD.lock();
+    let Some(keyboard) = keyboard.as_mut() else {
+        return;
+    };
+    while let Some(byte) = ps2::try_read_data() {
+        keyboard.handle_byte(byte);
+    }
+}
+
+/// Resets the PS/2 keyboard, then queues its key events from its IRQ handler
+///
+/// Must be called once, after `interrupts::init_controllers`
+pub(crate) fn init() -> Result<(), Ps2Error> {
+    let scancode_set = ps2::init()?;
+    *KEYBOARD.lock() = Some(Keyboard {
+        decoder: ScancodeDecoder::new(scancode_set),
+        modifiers: Modifiers::NONE,
+        pressed_keys: 0,
+        pending_leds: None,
+    });
+    irq::register_isa_irq_handler(KEYBOARD_ISA_IRQ, handle_keyboard_irq)
+        .expect("the keyboard IRQ must be free");
+    // A byte received before the IRQ was unmasked raised no interrupt, and would hold back the next ones
+    cpu::disable_interrupts();
+    handle_keyboard_irq();
+    cpu::enable_interrupts();
+    Ok(())
+}
+
+/// Pops the oldest key event not read yet
+///
+/// Must not be called from an interrupt handler, as the queue supports a single consumer
+pub(crate) fn read_key_event() -> Option<KeyEvent> {
+    // Safe : interrupt handlers never read key events, and the kernel runs on a single CPU
+    unsafe { KEY_EVENTS.pop() }
+}
@@ -0,0 +1,170 @@
+use crate::kernel::cpu::port;
+use crate::kernel::keyboard::scancode::ScancodeSet;
+
+const DATA_PORT: u16 = 0x60;
+/// Read for the status, written for the controller commands
+const STATUS_COMMAND_PORT: u16 = 0x64;
+
+const OUTPUT_BUFFER_FULL: u8 = 1 << 0;
+const INPUT_BUFFER_FULL: u8 = 1 << 1;
+
+const DISABLE_FIRST_PORT_COMMAND: u8 = 0xAD;
+const DISABLE_SECOND_PORT_COMMAND: u8 = 0xA7;
+const ENABLE_FIRST_PORT_COMMAND: u8 = 0xAE;
+const READ_CONFIGURATION_COMMAND: u8 = 0x20;
+const WRITE_CONFIGURATION_COMMAND: u8 = 0x60;
+const SELF_TEST_COMMAND: u8 = 0xAA;
+const SELF_TEST_PASSED: u8 = 0x55;
+const TEST_FIRST_PORT_COMMAND: u8 = 0xAB;
+const PORT_TEST_PASSED: u8 = 0x00;
+
+const FIRST_PORT_INTERRUPT: u8 = 1 << 0;
+const SECOND_PORT_INTERRUPT: u8 = 1 << 1;
+/// The controller translates set 2 scancodes into set 1 ones
+const FIRST_PORT_TRANSLATION: u8 = 1 << 6;
+
+pub(super) const SET_LEDS_COMMAND: u8 = 0xED;
+const SCANCODE_SET_COMMAND: u8 = 0xF0;
+const ENABLE_SCANNING_COMMAND: u8 = 0xF4;
+const RESET_COMMAND: u8 = 0xFF;
+pub(super) const ACKNOWLEDGE: u8 = 0xFA;
+const RESEND: u8 = 0xFE;
+const DEVICE_SELF_TEST_PASSED: u8 = 0xAA;
+const COMMAND_RETRY_COUNT: usize = 3;
+
+/// Status polls before giving up, each taking about a microsecond
+const TIMEOUT_POLL_COUNT: usize = 100_000;
+/// Keyboards may take hundreds of milliseconds to reset
+const RESET_TIMEOUT_POLL_COUNT: usize = 1_000_000;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Ps2Error {
+    /// Also happens when there is no PS/2 controller at all
+    Timeout,
+    ControllerSelfTestFailed,
+    PortTestFailed,
+    CommandNotAcknowledged,
+    KeyboardSelfTestFailed,
+}
+
+fn read_status() -> u8 {
+    // Safe : reading the status has no side effect
+    unsafe { port::read_u8(STATUS_COMMAND_PORT) }
+}
+
+/// Reads the pending byte sent by the controller or the keyboard, if any
+pub(super) fn try_read_data() -> Option<u8> {
+    if read_status() & OUTPUT_BUFFER_FULL == 0 {
+        return None;
+    }
+    // Safe : the byte was sent to the kernel, which is the only PS/2 user
+    Some(unsafe { port::read_u8(DATA_PORT) })
+}
+
+fn read_data(timeout_poll_count: usize) -> Result<u8, Ps2Error> {
+    for _ in 0..timeout_poll_count {
+        if let Some(byte) = try_read_data() {
+            return Ok(byte);
+        }
+        port::wait_a_bit();
+    }
+    Err(Ps2Error::Timeout)
+}
+
+fn wait_for_input_buffer() -> Result<(), Ps2Error> {
+    for _ in 0..TIMEOUT_POLL_COUNT {
+        if read_status() & INPUT_BUFFER_FULL == 0 {
+            return Ok(());
+        }
+        port::wait_a_bit();
+    }
+    Err(Ps2Error::Timeout)
+}
+
+fn write_command(command: u8) -> Result<(), Ps2Error> {
+    wait_for_input_buffer()?;
+    // Safe : the commands sent by this module keep the controller usable by the kernel
+    unsafe {
+        port::write_u8(STATUS_COMMAND_PORT, command);
+    }
+    Ok(())
+}
+
+/// Sends a byte to the keyboard, whose answer comes later
+pub(super) fn write_data(byte: u8) -> Result<(), Ps2Error> {
+    wait_for_input_buffer()?;
+    // Safe : the bytes sent by this module keep the keyboard usable by the kernel
+    unsafe {
+        port::write_u8(DATA_PORT, byte);
+    }
+    Ok(())
+}
+
+fn write_configuration(configuration: u8) -> Result<(), Ps2Error> {
+    write_command(WRITE_CONFIGURATION_COMMAND)?;
+    write_data(configuration)
+}
+
+/// Sends a byte to the keyboard and waits for its acknowledgement, sending it again if asked to
+fn write_keyboard_byte(byte: u8) -> Result<(), Ps2Error> {
+    for _ in 0..COMMAND_RETRY_COUNT {
+        write_data(byte)?;
+        match read_data(TIMEOUT_POLL_COUNT)? {
+            ACKNOWLEDGE => return Ok(()),
+            RESEND => continue,
+            _ => return Err(Ps2Error::CommandNotAcknowledged),
+        }
+    }
+    Err(Ps2Error::CommandNotAcknowledged)
+}
+
+fn reset_keyboard() -> Result<(), Ps2Error> {
+    write_keyboard_byte(RESET_COMMAND)?;
+    if read_data(RESET_TIMEOUT_POLL_COUNT)? != DEVICE_SELF_TEST_PASSED {
+        return Err(Ps2Error::KeyboardSelfTestFailed);
+    }
+    // Some keyboards send their ID after the self test
+    while read_data(TIMEOUT_POLL_COUNT).is_ok() {}
+    Ok(())
+}
+
+/// Resets the controller and the keyboard on its first port, then enables the keyboard IRQ
+///
+/// Set 2 scancodes are asked for, falling back to set 1 translated by the controller if the keyboard refuses.
+/// Must be called with the keyboard IRQ masked, as the answers are polled
+pub(super) fn init() -> Result<ScancodeSet, Ps2Error> {
+    write_command(DISABLE_FIRST_PORT_COMMAND)?;
+    write_command(DISABLE_SECOND_PORT_COMMAND)?;
+    while try_read_data().is_some() {}
+
+    write_command(READ_CONFIGURATION_COMMAND)?;
+    let mut configuration = read_data(TIMEOUT_POLL_COUNT)?;
+    configuration &= !(FIRST_PORT_INTERRUPT | SECOND_PORT_INTERRUPT | FIRST_PORT_TRANSLATION);
+    write_configuration(configuration)?;
+
+    write_command(SELF_TEST_COMMAND)?;
+    if read_data(TIMEOUT_POLL_COUNT)? != SELF_TEST_PASSED {
+        return Err(Ps2Error::ControllerSelfTestFailed);
+    }
+    // The self test may reset the controller
+    write_configuration(configuration)?;
+    write_command(TEST_FIRST_PORT_COMMAND)?;
+    if read_data(TIMEOUT_POLL_COUNT)? != PORT_TEST_PASSED {
+        return Err(Ps2Error::PortTestFailed);
+    }
+
+    write_command(ENABLE_FIRST_PORT_COMMAND)?;
+    reset_keyboard()?;
+    let scancode_set = match write_keyboard_byte(SCANCODE_SET_COMMAND)
+        .and_then(|_| write_keyboard_byte(ScancodeSet::Two as u8))
+    {
+        Ok(()) => ScancodeSet::Two,
+        Err(_) => {
+            configuration |= FIRST_PORT_TRANSLATION;
+            ScancodeSet::One
+        }
+    };
+    write_keyboard_byte(ENABLE_SCANNING_COMMAND)?;
+    write_configuration(configuration | FIRST_PORT_INTERRUPT)?;
+    Ok(scancode_set)
+}
@@ -0,0 +1,318 @@
+use crate::kernel::keyboard::KeyCode;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ScancodeSet {
+    One = 1,
+    Two = 2,
+}
+
+const EXTENDED_PREFIX: u8 = 0xE0;
+/// Only starts the pause key sequence, which has no release sequence
+const PAUSE_PREFIX: u8 = 0xE1;
+/// Set 2 only, set 1 release codes are the press codes with this bit set
+const SET_2_RELEASE_PREFIX: u8 = 0xF0;
+const SET_1_RELEASE_BIT: u8 = 0x80;
+/// Bytes following `PAUSE_PREFIX` : `1D 45 E1 9D C5` in set 1, `14 77 E1 F0 14 F0 77` in set 2
+const SET_1_PAUSE_REMAINING_BYTE_COUNT: u8 = 5;
+const SET_2_PAUSE_REMAINING_BYTE_COUNT: u8 = 7;
+
+/// Turns scancode bytes into key presses and releases
+#[derive(Debug)]
+pub(super) struct ScancodeDecoder {
+    set: ScancodeSet,
+    is_extended: bool,
+    is_release: bool,
+    pause_remaining_byte_count: u8,
+}
+
+impl ScancodeDecoder {
+    pub(super) const fn new(set: ScancodeSet) -> Self {
+        Self {
+            set,
+            is_extended: false,
+            is_release: false,
+            pause_remaining_byte_count: 0,
+        }
+    }
+
+    /// Returns the key and whether it's pressed once `byte` ends a scancode.
+    /// The pause key is reported as pressed only, the caller must release it
+    pub(super) fn decode(&mut self, byte: u8) -> Option<(KeyCode, bool)> {
+        if self.pause_remaining_byte_count > 0 {
+            self.pause_remaining_byte_count -= 1;
+            return if self.pause_remaining_byte_count == 0 {
+                Some((KeyCode::Pause, true))
+            } else {
+                None
+            };
+        }
+        match byte {
+            EXTENDED_PREFIX => {
+                self.is_extended = true;
+                return None;
+            }
+            PAUSE_PREFIX => {
+                self.pause_remaining_byte_count = match self.set {
+                    ScancodeSet::One => SET_1_PAUSE_REMAINING_BYTE_COUNT,
+                    ScancodeSet::Two => SET_2_PAUSE_REMAINING_BYTE_COUNT,
+                };
+                return None;
+            }
+            SET_2_RELEASE_PREFIX if self.set == ScancodeSet::Two => {
+                self.is_release = true;
+                return None;
+            }
+            _ => {}
+        }
+        let (code, is_release) = match self.set {
+            ScancodeSet::One => (byte & !SET_1_RELEASE_BIT, byte & SET_1_RELEASE_BIT != 0),
+            ScancodeSet::Two => (byte, self.is_release),
+        };
+        let key_code = match (self.set, self.is_extended) {
+            (ScancodeSet::One, false) => get_set_1_key_code(code),
+            (ScancodeSet::One, true) => get_set_1_extended_key_code(code),
+            (ScancodeSet::Two, false) => get_set_2_key_code(code),
+            (ScancodeSet::Two, true) => get_set_2_extended_key_code(code),
+        };
+        self.is_extended = false;
+        self.is_release = false;
+        // Unknown codes, and the fake shifts some keyboards send around extended keys, are ignored
+        key_code.map(|key_code| (key_code, !is_release))
+    }
+}
+
+fn get_set_1_key_code(code: u8) -> Option<KeyCode> {
+    use KeyCode::*;
+    Some(match code {
+        0x01 => Escape,
+        0x02 => Digit1,
+        0x03 => Digit2,
+        0x04 => Digit3,
+        0x05 => Digit4,
+        0x06 => Digit5,
+        0x07 => Digit6,
+        0x08 => Digit7,
+        0x09 => Digit8,
+        0x0A => Digit9,
+        0x0B => Digit0,
+        0x0C => Minus,
+        0x0D => Equal,
+        0x0E => Backspace,
+        0x0F => Tab,
+        0x10 => Q,
+        0x11 => W,
+        0x12 => E,
+        0x13 => R,
+        0x14 => T,
+        0x15 => Y,
+        0x16 => U,
+        0x17 => I,
+        0x18 => O,
+        0x19 => P,
+        0x1A => LeftBracket,
+        0x1B => RightBracket,
+        0x1C => Enter,
+        0x1D => LeftCtrl,
+        0x1E => A,
+        0x1F => S,
+        0x20 => D,
+        0x21 => F,
+        0x22 => G,
+        0x23 => H,
+        0x24 => J,
+        0x25 => K,
+        0x26 => L,
+        0x27 => Semicolon,
+        0x28 => Quote,
+        0x29 => Backquote,
+        0x2A => LeftShift,
+        0x2B => Backslash,
+        0x2C => Z,
+        0x2D => X,
+        0x2E => C,
+        0x2F => V,
+        0x30 => B,
+        0x31 => N,
+        0x32 => M,
+        0x33 => Comma,
+        0x34 => Period,
+        0x35 => Slash,
+        0x36 => RightShift,
+        0x37 => KeypadMultiply,
+        0x38 => LeftAlt,
+        0x39 => Space,
+        0x3A => CapsLock,
+        0x3B => F1,
+        0x3C => F2,
+        0x3D => F3,
+        0x3E => F4,
+        0x3F => F5,
+        0x40 => F6,
+        0x41 => F7,
+        0x42 => F8,
+        0x43 => F9,
+        0x44 => F10,
+        0x45 => NumLock,
+        0x46 => ScrollLock,
+        0x47 => Keypad7,
+        0x48 => Keypad8,
+        0x49 => Keypad9,
+        0x4A => KeypadMinus,
+        0x4B => Keypad4,
+        0x4C => Keypad5,
+        0x4D => Keypad6,
+        0x4E => KeypadPlus,
+        0x4F => Keypad1,
+        0x50 => Keypad2,
+        0x51 => Keypad3,
+        0x52 => Keypad0,
+        0x53 => KeypadPeriod,
+        0x56 => IntlBackslash,
+        0x57 => F11,
+        0x58 => F12,
+        _ => return None,
+    })
+}
+
+fn get_set_1_extended_key_code(code: u8) -> Option<KeyCode> {
+    use KeyCode::*;
+    Some(match code {
+        0x1C => KeypadEnter,
+        0x1D => RightCtrl,
+        0x35 => KeypadDivide,
+        0x37 => PrintScreen,
+        0x38 => RightAlt,
+        0x47 => Home,
+        0x48 => ArrowUp,
+        0x49 => PageUp,
+        0x4B => ArrowLeft,
+        0x4D => ArrowRight,
+        0x4F => End,
+        0x50 => ArrowDown,
+        0x51 => PageDown,
+        0x52 => Insert,
+        0x53 => Delete,
+        0x5B => LeftSuper,
+        0x5C => RightSuper,
+        0x5D => Menu,
+        _ => return None,
+    })
+}
+
+fn get_set_2_key_code(code: u8) -> Option<KeyCode> {
+    use KeyCode::*;
+    Some(match code {
+        0x01 => F9,
+        0x03 => F5,
+        0x04 => F3,
+        0x05 => F1,
+        0x06 => F2,
+        0x07 => F12,
+        0x09 => F10,
+        0x0A => F8,
+        0x0B => F6,
+        0x0C => F4,
+        0x0D => Tab,
+        0x0E => Backquote,
+        0x11 => LeftAlt,
+        0x12 => LeftShift,
+        0x14 => LeftCtrl,
+        0x15 => Q,
+        0x16 => Digit1,
+        0x1A => Z,
+        0x1B => S,
+        0x1C => A,
+        0x1D => W,
+        0x1E => Digit2,
+        0x21 => C,
+        0x22 => X,
+        0x23 => D,
+        0x24 => E,
+        0x25 => Digit4,
+        0x26 => Digit3,
+        0x29 => Space,
+        0x2A => V,
+        0x2B => F,
+        0x2C => T,
+        0x2D => R,
+        0x2E => Digit5,
+        0x31 => N,
+        0x32 => B,
+        0x33 => H,
+        0x34 => G,
+        0x35 => Y,
+        0x36 => Digit6,
+        0x3A => M,
+        0x3B => J,
+        0x3C => U,
+        0x3D => Digit7,
+        0x3E => Digit8,
+        0x41 => Comma,
+        0x42 => K,
+        0x43 => I,
+        0x44 => O,
+        0x45 => Digit0,
+        0x46 => Digit9,
+        0x49 => Period,
+        0x4A => Slash,
+        0x4B => L,
+        0x4C => Semicolon,
+        0x4D => P,
+        0x4E => Minus,
+        0x52 => Quote,
+        0x54 => LeftBracket,
+        0x55 => Equal,
+        0x58 => CapsLock,
+        0x59 => RightShift,
+        0x5A => Enter,
+        0x5B => RightBracket,
+        0x5D => Backslash,
+        0x61 => IntlBackslash,
+        0x66 => Backspace,
+        0x69 => Keypad1,
+        0x6B => Keypad4,
+        0x6C => Keypad7,
+        0x70 => Keypad0,
+        0x71 => KeypadPeriod,
+        0x72 => Keypad2,
+        0x73 => Keypad5,
+        0x74 => Keypad6,
+        0x75 => Keypad8,
+        0x76 => Escape,
+        0x77 => NumLock,
+        0x78 => F11,
+        0x79 => KeypadPlus,
+        0x7A => Keypad3,
+        0x7B => KeypadMinus,
+        0x7C => KeypadMultiply,
+        0x7D => Keypad9,
+        0x7E => ScrollLock,
+        0x83 => F7,
+        _ => return None,
+    })
+}
+
+fn get_set_2_extended_key_code(code: u8) -> Option<KeyCode> {
+    use KeyCode::*;
+    Some(match code {
+        0x11 => RightAlt,
+        0x14 => RightCtrl,
+        0x1F => LeftSuper,
+        0x27 => RightSuper,
+        0x2F => Menu,
+        0x4A => KeypadDivide,
+        0x5A => KeypadEnter,
+        0x69 => End,
+        0x6B => ArrowLeft,
+        0x6C => Home,
+        0x70 => Insert,
+        0x71 => Delete,
+        0x72 => ArrowDown,
+        0x74 => ArrowRight,
+        0x75 => ArrowUp,
+        0x7A => PageDown,
+        0x7C => PrintScreen,
+        0x7D => PageUp,
+        _ => return None,
+    })
+}
@@ -0,0 +1,84 @@
+use core::fmt::Write;
+use spin::Mutex;
+
+const LINE_CAPACITY: usize = 160;
+const DMESG_LINE_COUNT: usize = 256;
+
+/// A formatted log message, truncated to `LINE_CAPACITY` bytes so that logging never allocates
+#[derive(Clone, Copy)]
+pub(super) struct LogLine {
+    bytes: [u8; LINE_CAPACITY],
+    length: usize,
+}
+
+impl LogLine {
+    pub(super) const EMPTY: Self = Self {
+        bytes: [0; LINE_CAPACITY],
+        length: 0,
+    };
+
+    pub(super) fn as_str(&self) -> &str {
+        // Only whole chars are ever written
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_default()
+    }
+}
+
+impl Write for LogLine {
+    /// Never fails, truncating what doesn't fit
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let mut size = s.len().min(LINE_CAPACITY - self.length);
+        while !s.is_char_boundary(size) {
+            size -= 1;
+        }
+        self.bytes[self.length..self.length + size].copy_from_slice(&s.as_bytes()[..size]);
+        self.length += size;
+        Ok(())
+    }
+}
+
+/// The last `DMESG_LINE_COUNT` log lines, overwriting the oldest ones
+pub(super) struct Dmesg {
+    lines: [LogLine; DMESG_LINE_COUNT],
+    next_index: usize,
+    line_count: usize,
+}
+
+impl Dmesg {
+    const EMPTY: Self = Self {
+        lines: [LogLine::EMPTY; DMESG_LINE_COUNT],
+        next_index: 0,
+        line_count: 0,
+    };
+
+    pub(super) fn push(&mut self, line: &LogLine) {
+        self.lines[self.next_index] = *line;
+        self.next_index = (self.next_index + 1) % DMESG_LINE_COUNT;
+        self.line_count = (self.line_count + 1).min(DMESG_LINE_COUNT);
+    }
+
+    /// From the oldest to the newest line
+    fn iter_last(&self, count: usize) -> impl Iterator<Item = &str> {
+        let count = count.min(self.line_count);
+        let first_index = self.next_index + DMESG_LINE_COUNT - count;
+        (first_index..first_index + count)
+            .map(|index| self.lines[index % DMESG_LINE_COUNT].as_str())
+    }
+}
+
+pub(super) static DMESG: Mutex<Dmesg> = Mutex::new(Dmesg::EMPTY);
+
+/// Runs `f` on every kept log line, from the oldest to the newest
+///
+/// `f` must not log, as the lines are locked meanwhile
+pub(crate) fn for_each_dmesg_line(mut f: impl FnMut(&str)) {
+    crate::kernel::cpu::without_interrupts(|| {
+        DMESG.lock().iter_last(DMESG_LINE_COUNT).for_each(&mut f);
+    });
+}
+
+/// Runs `f` on the last `count` log lines, unless they are locked, which may be the case when panicking
+pub(crate) fn try_for_each_last_dmesg_line(count: usize, f: impl FnMut(&str)) {
+    if let Some(dmesg) = DMESG.try_lock() {
+        dmesg.iter_last(count).for_each(f);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::kernel::cpu;
+use crate::kernel::logger::dmesg::{LogLine, DMESG};
+use crate::kernel::time;
+use core::fmt::Write;
+use log::{LevelFilter, Log, Metadata, Record};
+use spin::Mutex;
+
+pub(crate) use dmesg::{for_each_dmesg_line, try_for_each_last_dmesg_line};
+
+mod dmesg;
+
+/// Kept lines are usually read after a failure, so they include the debug messages
+const DMESG_LEVEL_FILTER: LevelFilter = LevelFilter::Debug;
+const MAX_SINK_COUNT: usize = 4;
+
+/// Something printing the log lines, each line coming without its line feed
+#[derive(Clone, Copy)]
+struct LogSink {
+    write_line: fn(&str),
+    level_filter: LevelFilter,
+}
+
+static SINKS: Mutex<[Option<LogSink>; MAX_SINK_COUNT]> = Mutex::new([None; MAX_SINK_COUNT]);
+static LOGGER: KernelLogger = KernelLogger;
+
+/// Formats the records as `[seconds.microseconds since boot] LEVEL target: message`, keeps them in the dmesg lines,
+/// then writes them to the sinks
+struct KernelLogger;
+
+impl Log for KernelLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let uptime = time::get_uptime();
+        let mut line = LogLine::EMPTY;
+        let _ = write!(
+            line,
+            "[{:>5}.{:06}] {:<5} {}: {}",
+            uptime.as_secs(),
+            uptime.subsec_micros(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        // Interrupt handlers may log too, so they mustn't find the locks taken
+        let sinks = cpu::without_interrupts(|| {
+            if record.level() <= DMESG_LEVEL_FILTER {
+                DMESG.lock().push(&line);
+            }
+            *SINKS.lock()
+        });
+        for sink in sinks.iter().flatten() {
+            if record.level() <= sink.level_filter {
+                (sink.write_line)(line.as_str());
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the kernel logger, which only keeps the lines until sinks are added
+///
+/// Must be called once
+pub(crate) fn init() {
+    log::set_logger(&LOGGER).expect("the kernel logger must be installed once");
+    log::set_max_level(DMESG_LEVEL_FILTER);
+}
+
+/// Writes the next log lines up to `level_filter` with `write_line`
+///
+/// # Panics
+/// Panics if there are already `MAX_SINK_COUNT` sinks
+pub(crate) fn add_sink(write_line: fn(&str), level_filter: LevelFilter) {
+    cpu::without_interrupts(|| {
+        let mut sinks = SINKS.lock();
+        let free_sink = sinks
+            .iter_mut()
+            .find(|sink| sink.is_none())
+            .expect("too many log sinks");
+        *free_sink = Some(LogSink {
+            write_line,
+            level_filter,
+        });
+        log::set_max_level(log::max_level().max(level_filter));
+    });
+}
@@ -0,0 +1,303 @@
+use crate::kernel::memory::{PhysicalRange, PAGE_SIZE};
+use core::slice;
+use spin::Mutex;
+use uefi::table::boot::{MemoryDescriptor, MemoryMap, MemoryType};
+
+/// Frames below 1 MiB are never handed out : the null frame must stay unused, and the legacy area
+/// may be needed later (real mode trampolines, BIOS data area...)
+const LOW_MEMORY: PhysicalRange = PhysicalRange {
+    start: 0,
+    end: 0x10_0000,
+};
+
+const FRAMES_PER_BITMAP_WORD: usize = u64::BITS as usize;
+
+static FRAME_ALLOCATOR: Mutex<Option<FrameAllocator>> = Mutex::new(None);
+
+/// A 4 KiB physical page frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct PhysicalFrame(u64);
+
+impl PhysicalFrame {
+    pub(crate) const fn containing_address(address: u64) -> Self {
+        Self(address / PAGE_SIZE)
+    }
+    pub(crate) const fn start_address(&self) -> u64 {
+        self.0 * PAGE_SIZE
+    }
+    const fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Only conventional memory is free once the boot services are exited
+///
+/// The boot services regions still hold our stack and are handled as reserved until they get reclaimed,
+/// and the loader regions hold the kernel image and everything allocated while booting.
+/// Other regions (runtime services, ACPI, MMIO...) are never handed out.
+fn is_free_memory(memory_type: MemoryType) -> bool {
+    memory_type == MemoryType::CONVENTIONAL
+}
+
+fn is_tracked_memory(memory_type: MemoryType) -> bool {
+    matches!(
+        memory_type,
+        MemoryType::CONVENTIONAL
+            | MemoryType::BOOT_SERVICES_CODE
+            | MemoryType::BOOT_SERVICES_DATA
+            | MemoryType::LOADER_CODE
+            | MemoryType::LOADER_DATA
+    )
+}
+
+fn is_boot_services_memory(memory_type: MemoryType) -> bool {
+    matches!(
+        memory_type,
+        MemoryType::BOOT_SERVICES_CODE | MemoryType::BOOT_SERVICES_DATA
+    )
+}
+
+fn get_descriptor_range(descriptor: &MemoryDescriptor) -> PhysicalRange {
+    PhysicalRange::from_start_and_page_count(descriptor.phys_start, descriptor.page_count)
+}
+
+#[derive(Debug)]
+pub(crate) struct FrameAllocator {
+    /// One bit per frame, set when the frame is in use or unavailable
+    bitmap: &'static mut [u64],
+    frame_count: usize,
+    free_frame_count: usize,
+    /// Every frame before this index is in use
+    first_maybe_free_frame_index: usize,
+}
+
+impl FrameAllocator {
+    /// The bitmap is stored in the first conventional memory region large enough,
+    /// whose frames are then marked as used.
+    ///
+    /// Returns `None` if no conventional memory region can hold the bitmap
+    fn from_memory_map(
+        memory_map: &MemoryMap<'static>,
+        reserved_ranges: &[PhysicalRange],
+    ) -> Option<Self> {
+        let frame_count = memory_map
+            .entries()
+            .filter(|descriptor| is_tracked_memory(descriptor.ty))
+            .map(|descriptor| get_descriptor_range(descriptor).end / PAGE_SIZE)
+            .max()? as usize;
+        let word_count = frame_count.div_ceil(FRAMES_PER_BITMAP_WORD);
+        let bitmap_range = Self::find_bitmap_range(memory_map, word_count, reserved_ranges)?;
+        // Safe :
+        // - The range is conventional memory, which nobody uses once the boot services are exited
+        // - UEFI identity maps all the memory it describes
+        let bitmap =
+            unsafe { slice::from_raw_parts_mut(bitmap_range.start as *mut u64, word_count) };
+        bitmap.fill(u64::MAX);
+        let mut frame_allocator = Self {
+            bitmap,
+            frame_count,
+            free_frame_count: 0,
+            first_maybe_free_frame_index: 0,
+        };
+        for descriptor in memory_map.entries() {
+            if is_free_memory(descriptor.ty) {
+                frame_allocator.mark_range_free(get_descriptor_range(descriptor));
+            }
+        }
+        frame_allocator.mark_range_used(LOW_MEMORY);
+        frame_allocator.mark_range_used(bitmap_range);
+        for reserved_range in reserved_ranges {
+            frame_allocator.mark_range_used(*reserved_range);
+        }
+        Some(frame_allocator)
+    }
+    fn find_bitmap_range(
+        memory_map: &MemoryMap<'static>,
+        word_count: usize,
+        reserved_ranges: &[PhysicalRange],
+    ) -> Option<PhysicalRange> {
+        let size = (word_count * core::mem::size_of::<u64>()) as u64;
+        memory_map
+            .entries()
+            .filter(|descriptor| is_free_memory(descriptor.ty))
+            .map(get_descriptor_range)
+            .filter_map(|region| {
+                let start = region.start.max(LOW_MEMORY.end);
+                let candidate = PhysicalRange::from_start_and_size(start, size);
+                (candidate.end <= region.end).then_some(candidate)
+            })
+            .find(|candidate| {
+                !reserved_ranges
+                    .iter()
+                    .any(|reserved_range| reserved_range.overlaps(candidate))
+            })
+    }
+
+    pub(crate) const fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+    pub(crate) const fn free_frame_count(&self) -> usize {
+        self.free_frame_count
+    }
+
+    pub(crate) fn allocate_frame(&mut self) -> Option<PhysicalFrame> {
+        let first_word_index = self.first_maybe_free_frame_index / FRAMES_PER_BITMAP_WORD;
+        for word_index in first_word_index..self.bitmap.len() {
+            let word = self.bitmap[word_index];
+            if word == u64::MAX {
+                continue;
+            }
+            let index = word_index * FRAMES_PER_BITMAP_WORD + word.trailing_ones() as usize;
+            if index >= self.frame_count {
+                break;
+            }
+            self.set_used(index);
+            self.first_maybe_free_frame_index = index + 1;
+            return Some(PhysicalFrame(index as u64));
+        }
+        self.first_maybe_free_frame_index = self.frame_count;
+        None
+    }
+    /// Returns the first frame of `count` physically contiguous frames, as needed for DMA buffers
+    pub(crate) fn allocate_contiguous_frames(&mut self, count: usize) -> Option<PhysicalFrame> {
+        let mut run_start = self.first_maybe_free_frame_index;
+        let mut run_length = 0;
+        for index in self.first_maybe_free_frame_index..self.frame_count {
+            if self.is_used(index) {
+                run_start = index + 1;
+                run_length = 0;
+                continue;
+            }
+            run_length += 1;
+            if run_length == count {
+                for index in run_start..run_start + count {
+                    self.set_used(index);
+                }
+                return Some(PhysicalFrame(run_start as u64));
+            }
+        }
+        None
+    }
+    /// # Panics
+    /// Panics if `frame` isn't in use, which is a double free
+    pub(crate) fn deallocate_frame(&mut self, frame: PhysicalFrame) {
+        let index = frame.index();
+        assert!(
+            index < self.frame_count && self.is_used(index),
+            "double free of the physical frame at {:#x}",
+            frame.start_address()
+        );
+        self.set_free(index);
+    }
+    /// # Panics
+    /// Panics if one of the frames isn't in use, which is a double free
+    pub(crate) fn deallocate_contiguous_frames(
+        &mut self,
+        first_frame: PhysicalFrame,
+        count: usize,
+    ) {
+        for index in first_frame.index()..first_frame.index() + count {
+            self.deallocate_frame(PhysicalFrame(index as u64));
+        }
+    }
+
+    /// The frames below 1 MiB stay unused, like at initialization
+    ///
+    /// Returns the number of frames freed
+    fn reclaim_boot_services_memory(&mut self, memory_map: &MemoryMap<'static>) -> usize {
+        let mut reclaimed_frame_count = 0;
+        for descriptor in memory_map
+            .entries()
+            .filter(|descriptor| is_boot_services_memory(descriptor.ty))
+        {
+            let range = get_descriptor_range(descriptor);
+            let first_frame = PhysicalFrame::containing_address(range.start.max(LOW_MEMORY.end));
+            let end_frame = PhysicalFrame::containing_address(range.end);
+            if first_frame < end_frame {
+                // Never free until now, so never handed out
+                let count = (end_frame.0 - first_frame.0) as usize;
+                self.deallocate_contiguous_frames(first_frame, count);
+                reclaimed_frame_count += count;
+            }
+        }
+        reclaimed_frame_count
+    }
+
+    fn is_used(&self, index: usize) -> bool {
+        self.bitmap[index / FRAMES_PER_BITMAP_WORD] & (1 << (index % FRAMES_PER_BITMAP_WORD)) != 0
+    }
+    fn set_used(&mut self, index: usize) {
+        if !self.is_used(index) {
+            self.bitmap[index / FRAMES_PER_BITMAP_WORD] |= 1 << (index % FRAMES_PER_BITMAP_WORD);
+            self.free_frame_count -= 1;
+        }
+    }
+    fn set_free(&mut self, index: usize) {
+        if self.is_used(index) {
+            self.bitmap[index / FRAMES_PER_BITMAP_WORD] &= !(1 << (index % FRAMES_PER_BITMAP_WORD));
+            self.free_frame_count += 1;
+            self.first_maybe_free_frame_index = self.first_maybe_free_frame_index.min(index);
+        }
+    }
+    /// Only the frames entirely inside `range` are freed
+    fn mark_range_free(&mut self, range: PhysicalRange) {
+        let first_index = range.start.div_ceil(PAGE_SIZE) as usize;
+        let end_index = ((range.end / PAGE_SIZE) as usize).min(self.frame_count);
+        for index in first_index..end_index {
+            self.set_free(index);
+        }
+    }
+    /// Every frame overlapping `range` is marked as used
+    fn mark_range_used(&mut self, range: PhysicalRange) {
+        let first_index = (range.start / PAGE_SIZE) as usize;
+        let end_index = (range.end.div_ceil(PAGE_SIZE) as usize).min(self.frame_count);
+        for index in first_index..end_index {
+            self.set_used(index);
+        }
+    }
+}
+
+/// Builds the global frame allocator from the memory map handed over by UEFI
+///
+/// # Panics
+/// Panics if no conventional memory region can hold the frame bitmap
+pub(crate) fn init(memory_map: &MemoryMap<'static>, reserved_ranges: &[PhysicalRange]) {
+    let frame_allocator = FrameAllocator::from_memory_map(memory_map, reserved_ranges)
+        .expect("no conventional memory region can hold the frame allocator bitmap");
+    log::info!(
+        "{} free frames out of {}",
+        frame_allocator.free_frame_count(),
+        frame_allocator.frame_count()
+    );
+    *FRAME_ALLOCATOR.lock() = Some(frame_allocator);
+}
+
+/// Hands the boot services regions over to the global frame allocator, returning the number of frames reclaimed
+///
+/// # Safety
+/// Nothing may refer to the boot services memory anymore : the kernel must run on its own stack, and everything
+/// still needed from the firmware must have been copied out
+pub(crate) unsafe fn reclaim_boot_services_memory(memory_map: &MemoryMap<'static>) -> usize {
+    with_frame_allocator(|frame_allocator| frame_allocator.reclaim_boot_services_memory(memory_map))
+}
+
+/// Runs `f` with the global frame allocator locked
+///
+/// Must not be called from an interrupt handler, which could deadlock
+///
+/// # Panics
+/// Panics if the frame allocator isn't initialized yet
+pub(crate) fn with_frame_allocator<R>(f: impl FnOnce(&mut FrameAllocator) -> R) -> R {
+    let mut frame_allocator = FRAME_ALLOCATOR.lock();
+    f(frame_allocator
+        .as_mut()
+        .expect("the frame allocator must be initialized first"))
+}
+
+pub(crate) fn allocate_frame() -> Option<PhysicalFrame> {
+    with_frame_allocator(|frame_allocator| frame_allocator.allocate_frame())
+}
+
+pub(crate) fn deallocate_frame(frame: PhysicalFrame) {
+    with_frame_allocator(|frame_allocator| frame_allocator.deallocate_frame(frame));
+}
@@ -0,0 +1,211 @@
+//! The kernel heap, behind the global allocator
+//!
+//! Allocation failures take the default path : `alloc` panics with the size asked for, and the panic handler
+//! reports it through the console with the heap statistics. An `#[alloc_error_handler]` would need the unstable
+//! feature of the same name, while the kernel builds on the stable toolchain pinned in `rust-toolchain.toml`, with
+//! the prebuilt `core` and `alloc` of the `x86_64-unknown-uefi` target.
+
+use crate::kernel::memory::frame_allocator::with_frame_allocator;
+use crate::kernel::memory::{PhysicalRange, PAGE_SIZE};
+use core::alloc::{GlobalAlloc, Layout};
+use core::{mem, ptr};
+use spin::Mutex;
+
+/// The heap is carved out of the free physical memory at boot, and never grows
+const MAX_HEAP_SIZE: u64 = 32 * 1024 * 1024;
+const MIN_HEAP_SIZE: u64 = 256 * 1024;
+
+/// Every block address and size is a multiple of this, so that any freed block can hold a `FreeBlock`
+const BLOCK_GRANULARITY: usize = 16;
+const MIN_BLOCK_SIZE: usize = mem::size_of::<FreeBlock>();
+
+#[global_allocator]
+static HEAP: LockedHeap = LockedHeap(Mutex::new(Heap::EMPTY));
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HeapStats {
+    pub(crate) size: usize,
+    pub(crate) used: usize,
+    /// The highest `used` value ever reached
+    pub(crate) peak: usize,
+}
+
+impl HeapStats {
+    pub(crate) const fn free(&self) -> usize {
+        self.size - self.used
+    }
+}
+
+/// Header written at the start of every free block, linking the free blocks in address order
+struct FreeBlock {
+    size: usize,
+    next: *mut FreeBlock,
+}
+
+/// A first-fit allocator over a linked list of free blocks, merging neighbour blocks when freeing
+struct Heap {
+    /// Sentinel whose `next` is the free block with the lowest address
+    head: FreeBlock,
+    stats: HeapStats,
+}
+
+// Safe : the free blocks are only reached through the heap, which is only reached through its mutex
+unsafe impl Send for Heap {}
+
+impl Heap {
+    const EMPTY: Self = Self {
+        head: FreeBlock {
+            size: 0,
+            next: ptr::null_mut(),
+        },
+        stats: HeapStats {
+            size: 0,
+            used: 0,
+            peak: 0,
+        },
+    };
+
+    /// # Safety
+    /// `range` must be unused, identity mapped, and never used by anything else
+    unsafe fn init(&mut self, range: PhysicalRange) {
+        let size = range.size() as usize;
+        Self::link_block(&mut self.head, range.start as usize, size);
+        self.stats.size = size;
+    }
+
+    const fn get_block_size(layout: Layout) -> usize {
+        let size = if layout.size() < MIN_BLOCK_SIZE {
+            MIN_BLOCK_SIZE
+        } else {
+            layout.size()
+        };
+        size.next_multiple_of(BLOCK_GRANULARITY)
+    }
+    const fn get_block_align(layout: Layout) -> usize {
+        if layout.align() < BLOCK_GRANULARITY {
+            BLOCK_GRANULARITY
+        } else {
+            layout.align()
+        }
+    }
+
+    /// Inserts a new free block after `previous`, and returns it
+    unsafe fn link_block(previous: *mut FreeBlock, start: usize, size: usize) -> *mut FreeBlock {
+        let block = start as *mut FreeBlock;
+        block.write(FreeBlock {
+            size,
+            next: (*previous).next,
+        });
+        (*previous).next = block;
+        block
+    }
+
+    /// Returns a null pointer if no free block is large enough
+    unsafe fn allocate(&mut self, layout: Layout) -> *mut u8 {
+        let size = Self::get_block_size(layout);
+        let align = Self::get_block_align(layout);
+        let mut previous: *mut FreeBlock = &mut self.head;
+        while !(*previous).next.is_null() {
+            let block = (*previous).next;
+            let block_start = block as usize;
+            let block_end = block_start + (*block).size;
+            let allocation_start = block_start.next_multiple_of(align);
+            let allocation_end = allocation_start + size;
+            if allocation_end <= block_end {
+                // Both leftovers are multiples of BLOCK_GRANULARITY, so they're either empty or large enough
+                (*previous).next = (*block).next;
+                let mut last_leftover = previous;
+                if allocation_start > block_start {
+                    last_leftover = Self::link_block(
+                        last_leftover,
+                        block_start,
+                        allocation_start - block_start,
+                    );
+                }
+                if block_end > allocation_end {
+                    Self::link_block(last_leftover, allocation_end, block_end - allocation_end);
+                }
+                self.stats.used += size;
+                self.stats.peak = self.stats.peak.max(self.stats.used);
+                return allocation_start as *mut u8;
+            }
+            previous = block;
+        }
+        ptr::null_mut()
+    }
+
+    unsafe fn deallocate(&mut self, pointer: *mut u8, layout: Layout) {
+        let size = Self::get_block_size(layout);
+        let start = pointer as usize;
+        let head: *mut FreeBlock = &mut self.head;
+        let mut previous = head;
+        while !(*previous).next.is_null() && ((*previous).next as usize) < start {
+            previous = (*previous).next;
+        }
+        let block = Self::link_block(previous, start, size);
+        let next = (*block).next;
+        if !next.is_null() && start + size == next as usize {
+            (*block).size += (*next).size;
+            (*block).next = (*next).next;
+        }
+        if previous != head && previous as usize + (*previous).size == start {
+            (*previous).size += (*block).size;
+            (*previous).next = (*block).next;
+        }
+        self.stats.used -= size;
+    }
+}
+
+struct LockedHeap(Mutex<Heap>);
+
+unsafe impl GlobalAlloc for LockedHeap {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.0.lock().allocate(layout)
+    }
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        self.0.lock().deallocate(pointer, layout);
+    }
+}
+
+/// Carves the heap out of the free physical memory : up to `MAX_HEAP_SIZE`, and at most half of the free memory
+///
+/// # Panics
+/// Panics if not even `MIN_HEAP_SIZE` contiguous bytes are available
+pub(crate) fn init() {
+    let heap_range = with_frame_allocator(|frame_allocator| {
+        let free_memory_size = frame_allocator.free_frame_count() as u64 * PAGE_SIZE;
+        let mut size = MAX_HEAP_SIZE.min(free_memory_size / 2);
+        while size >= MIN_HEAP_SIZE {
+            let page_count = size / PAGE_SIZE;
+            if let Some(first_frame) =
+                frame_allocator.allocate_contiguous_frames(page_count as usize)
+            {
+                return Some(PhysicalRange::from_start_and_page_count(
+                    first_frame.start_address(),
+                    page_count,
+                ));
+            }
+            size /= 2;
+        }
+        None
+    })
+    .expect("not enough contiguous physical memory for the kernel heap");
+    // Safe : the frames were just allocated for the heap only, and UEFI identity maps all the memory it describes
+    unsafe {
+        HEAP.0.lock().init(heap_range);
+    }
+    log::info!(
+        "{} KiB of heap at {:#x}",
+        heap_range.size() / 1024,
+        heap_range.start
+    );
+}
+
+pub(crate) fn get_stats() -> HeapStats {
+    HEAP.0.lock().stats
+}
+
+/// Doesn't wait for the heap, which may be locked by the code that panicked
+pub(crate) fn try_get_stats() -> Option<HeapStats> {
+    HEAP.0.try_lock().map(|heap| heap.stats)
+}
@@ -0,0 +1,160 @@
+use crate::kernel::memory::{PhysicalRange, PAGE_SIZE};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use uefi::table::boot::{MemoryAttribute, MemoryMap, MemoryType};
+
+const MIB: u64 = 1024 * 1024;
+
+/// How the kernel may use some memory
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MemoryKind {
+    /// Free once the boot services are exited, reclaimed or not
+    Usable,
+    /// Kept by the firmware, like the runtime services
+    Reserved,
+    /// The ACPI tables, and the memory kept by the ACPI firmware
+    Acpi,
+    /// Mapped to devices
+    Mmio,
+}
+
+impl MemoryKind {
+    pub(crate) const fn of(memory_type: MemoryType) -> Self {
+        match memory_type {
+            MemoryType::CONVENTIONAL
+            | MemoryType::BOOT_SERVICES_CODE
+            | MemoryType::BOOT_SERVICES_DATA
+            | MemoryType::LOADER_CODE
+            | MemoryType::LOADER_DATA => Self::Usable,
+            MemoryType::ACPI_RECLAIM | MemoryType::ACPI_NON_VOLATILE => Self::Acpi,
+            MemoryType::MMIO | MemoryType::MMIO_PORT_SPACE => Self::Mmio,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+/// Contiguous descriptors of the memory map with the same type and attributes
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MemoryRange {
+    pub(crate) memory_type: MemoryType,
+    pub(crate) range: PhysicalRange,
+    pub(crate) attributes: MemoryAttribute,
+}
+
+impl MemoryRange {
+    pub(crate) const fn page_count(&self) -> u64 {
+        self.range.size() / PAGE_SIZE
+    }
+}
+
+impl Display for MemoryRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:#014x}-{:#014x} {:<18} {:>8} pages  attributes {:#x}",
+            self.range.start,
+            self.range.end,
+            get_type_name(self.memory_type),
+            self.page_count(),
+            self.attributes.bits()
+        )
+    }
+}
+
+/// The sizes of the memory of each kind, in bytes
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct MemoryTotals {
+    pub(crate) usable: u64,
+    pub(crate) reserved: u64,
+    pub(crate) acpi: u64,
+    pub(crate) mmio: u64,
+}
+
+impl MemoryTotals {
+    pub(crate) fn of(ranges: &[MemoryRange]) -> Self {
+        let mut totals = Self::default();
+        for range in ranges {
+            let total = match MemoryKind::of(range.memory_type) {
+                MemoryKind::Usable => &mut totals.usable,
+                MemoryKind::Reserved => &mut totals.reserved,
+                MemoryKind::Acpi => &mut totals.acpi,
+                MemoryKind::Mmio => &mut totals.mmio,
+            };
+            *total += range.range.size();
+        }
+        totals
+    }
+}
+
+impl Display for MemoryTotals {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} MiB usable, {} MiB reserved, {} KiB of ACPI, {} MiB of MMIO",
+            self.usable / MIB,
+            self.reserved / MIB,
+            self.acpi / 1024,
+            self.mmio / MIB
+        )
+    }
+}
+
+/// The descriptors sorted by address, the contiguous ones with the same type and attributes being merged
+pub(crate) fn get_merged_ranges(memory_map: &MemoryMap) -> Vec<MemoryRange> {
+    let mut descriptors: Vec<_> = memory_map.entries().collect();
+    descriptors.sort_unstable_by_key(|descriptor| descriptor.phys_start);
+    let mut ranges: Vec<MemoryRange> = Vec::with_capacity(descriptors.len());
+    for descriptor in descriptors {
+        let range =
+            PhysicalRange::from_start_and_page_count(descriptor.phys_start, descriptor.page_count);
+        match ranges.last_mut() {
+            Some(last)
+                if last.memory_type == descriptor.ty
+                    && last.attributes == descriptor.att
+                    && last.range.end == range.start =>
+            {
+                last.range.end = range.end;
+            }
+            _ => ranges.push(MemoryRange {
+                memory_type: descriptor.ty,
+                range,
+                attributes: descriptor.att,
+            }),
+        }
+    }
+    ranges
+}
+
+/// Logs the merged ranges as debug lines, on COM1 and in dmesg, then the totals
+pub(crate) fn log_report(memory_map: &MemoryMap) {
+    let ranges = get_merged_ranges(memory_map);
+    for range in &ranges {
+        log::debug!("{}", range);
+    }
+    log::info!(
+        "memory map : {} ranges, {}",
+        ranges.len(),
+        MemoryTotals::of(&ranges)
+    );
+}
+
+const fn get_type_name(memory_type: MemoryType) -> &'static str {
+    match memory_type {
+        MemoryType::RESERVED => "reserved",
+        MemoryType::LOADER_CODE => "loader code",
+        MemoryType::LOADER_DATA => "loader data",
+        MemoryType::BOOT_SERVICES_CODE => "boot services code",
+        MemoryType::BOOT_SERVICES_DATA => "boot services data",
+        MemoryType::RUNTIME_SERVICES_CODE => "runtime code",
+        MemoryType::RUNTIME_SERVICES_DATA => "runtime data",
+        MemoryType::CONVENTIONAL => "conventional",
+        MemoryType::UNUSABLE => "unusable",
+        MemoryType::ACPI_RECLAIM => "ACPI reclaimable",
+        MemoryType::ACPI_NON_VOLATILE => "ACPI non-volatile",
+        MemoryType::MMIO => "MMIO",
+        MemoryType::MMIO_PORT_SPACE => "MMIO port space",
+        MemoryType::PAL_CODE => "PAL code",
+        MemoryType::PERSISTENT_MEMORY => "persistent",
+        _ => "other",
+    }
+}
@@ -0,0 +1,31 @@
+pub(crate) mod frame_allocator;
+pub(crate) mod heap;
+pub(crate) mod memory_map;
+pub(crate) mod paging;
+
+pub(crate) const PAGE_SIZE: u64 = 4096;
+
+/// A range of physical addresses, `end` being excluded
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PhysicalRange {
+    pub(crate) start: u64,
+    pub(crate) end: u64,
+}
+
+impl PhysicalRange {
+    pub(crate) const fn from_start_and_size(start: u64, size: u64) -> Self {
+        Self {
+            start,
+            end: start + size,
+        }
+    }
+    pub(crate) const fn from_start_and_page_count(start: u64, page_count: u64) -> Self {
+        Self::from_start_and_size(start, page_count * PAGE_SIZE)
+    }
+    pub(crate) const fn size(&self) -> u64 {
+        self.end - self.start
+    }
+    pub(crate) const fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
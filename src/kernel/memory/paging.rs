@@ -0,0 +1,491 @@
+//! The kernel-owned page tables
+//!
+//! The kernel still runs from its identity mapped image, its static data included : the higher half alias of the
+//! image is mapped, but never jumped to, as the image was relocated by UEFI for its load address. Running from the
+//! alias, and unmapping the low identity window that user mode would need, is left to come
+
+use crate::kernel::cpu;
+use crate::kernel::memory::frame_allocator;
+use crate::kernel::memory::frame_allocator::PhysicalFrame;
+use crate::kernel::memory::{PhysicalRange, PAGE_SIZE};
+use core::ops::BitOr;
+use core::ptr;
+use core::sync::atomic::{AtomicU64, Ordering};
+use spin::Mutex;
+use uefi::table::boot::{MemoryMap, MemoryType};
+
+/// The whole physical memory is mapped from here, so that any frame (page tables first) can be reached
+pub(crate) const DIRECT_MAP_ADDRESS: u64 = 0xFFFF_8000_0000_0000;
+/// Higher half alias of the kernel image, mapped section by section with the right permissions, but unused yet
+pub(crate) const KERNEL_IMAGE_ADDRESS: u64 = 0xFFFF_FFFF_8000_0000;
+pub(crate) const FRAME_BUFFER_ADDRESS: u64 = 0xFFFF_FFFF_C000_0000;
+/// Kernel stacks are mapped from here, each one above an unmapped guard page
+const KERNEL_STACKS_ADDRESS: u64 = 0xFFFF_FF00_0000_0000;
+
+const ENTRY_COUNT: usize = 512;
+const HUGE_PAGE_SIZE: u64 = 2 * 1024 * 1024;
+const ADDRESS_MASK: u64 = 0x000F_FFFF_FFFF_F000;
+/// At least the first 4 GiB are identity mapped, as they hold the MMIO regions of most devices
+const MIN_IDENTITY_MAPPED_SIZE: u64 = 4 * 1024 * 1024 * 1024;
+
+/// Offset between a physical address and the virtual address where the kernel can reach it
+///
+/// Null while the UEFI identity mapping is active, `DIRECT_MAP_ADDRESS` afterwards
+static PHYSICAL_MEMORY_OFFSET: AtomicU64 = AtomicU64::new(0);
+
+static KERNEL_ADDRESS_SPACE: Mutex<Option<AddressSpace>> = Mutex::new(None);
+
+/// Bottom of the guard page of the next kernel stack
+static NEXT_KERNEL_STACK_ADDRESS: AtomicU64 = AtomicU64::new(KERNEL_STACKS_ADDRESS);
+
+pub(crate) fn physical_to_virtual(physical_address: u64) -> u64 {
+    physical_address + PHYSICAL_MEMORY_OFFSET.load(Ordering::Relaxed)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PageFlags(u64);
+
+impl PageFlags {
+    pub(crate) const PRESENT: Self = Self(1);
+    pub(crate) const WRITABLE: Self = Self(1 << 1);
+    pub(crate) const USER: Self = Self(1 << 2);
+    pub(crate) const WRITE_THROUGH: Self = Self(1 << 3);
+    pub(crate) const NO_CACHE: Self = Self(1 << 4);
+    const HUGE: Self = Self(1 << 7);
+    pub(crate) const GLOBAL: Self = Self(1 << 8);
+    pub(crate) const NO_EXECUTE: Self = Self(1 << 63);
+
+    pub(crate) const KERNEL_READ_ONLY_DATA: Self = Self(Self::PRESENT.0 | Self::NO_EXECUTE.0);
+    pub(crate) const KERNEL_DATA: Self =
+        Self(Self::PRESENT.0 | Self::WRITABLE.0 | Self::NO_EXECUTE.0);
+    pub(crate) const KERNEL_MMIO: Self = Self(Self::KERNEL_DATA.0 | Self::NO_CACHE.0);
+    /// Flags of the non-leaf entries : permissions are only restricted by the leaf entries
+    const TABLE: Self = Self(Self::PRESENT.0 | Self::WRITABLE.0 | Self::USER.0);
+
+    pub(crate) const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    const fn without(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl BitOr for PageFlags {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum PagingError {
+    FrameAllocationFailed,
+    AlreadyMapped,
+    NotMapped,
+    /// The address is mapped by a 1 GiB page, which are never split
+    InsideGiantPage,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+struct PageTableEntry(u64);
+
+impl PageTableEntry {
+    const UNUSED: Self = Self(0);
+
+    const fn new(physical_address: u64, flags: PageFlags) -> Self {
+        Self(physical_address & ADDRESS_MASK | flags.0)
+    }
+    const fn is_present(&self) -> bool {
+        self.flags().contains(PageFlags::PRESENT)
+    }
+    const fn is_huge(&self) -> bool {
+        self.flags().contains(PageFlags::HUGE)
+    }
+    const fn physical_address(&self) -> u64 {
+        self.0 & ADDRESS_MASK
+    }
+    const fn flags(&self) -> PageFlags {
+        PageFlags(self.0 & !ADDRESS_MASK)
+    }
+}
+
+#[repr(C, align(4096))]
+struct PageTable([PageTableEntry; ENTRY_COUNT]);
+
+impl PageTable {
+    /// # Safety
+    /// `physical_address` must be the address of a page table, reachable through `physical_to_virtual`,
+    /// and the returned reference mustn't outlive the table nor be aliased
+    unsafe fn from_physical_address<'a>(physical_address: u64) -> &'a mut Self {
+        &mut *(physical_to_virtual(physical_address) as *mut Self)
+    }
+    fn allocate() -> Result<PhysicalFrame, PagingError> {
+        let frame = frame_allocator::allocate_frame().ok_or(PagingError::FrameAllocationFailed)?;
+        // Safe : the frame was just allocated for this table only
+        unsafe {
+            ptr::write(
+                physical_to_virtual(frame.start_address()) as *mut Self,
+                Self([PageTableEntry::UNUSED; ENTRY_COUNT]),
+            );
+        }
+        Ok(frame)
+    }
+}
+
+/// Indices of a virtual address in the 4 levels of tables, from the PML4 down to the page table
+const fn get_table_indices(virtual_address: u64) -> [usize; 4] {
+    [
+        (virtual_address >> 39) as usize % ENTRY_COUNT,
+        (virtual_address >> 30) as usize % ENTRY_COUNT,
+        (virtual_address >> 21) as usize % ENTRY_COUNT,
+        (virtual_address >> 12) as usize % ENTRY_COUNT,
+    ]
+}
+
+/// Level of the tables whose entries may map 2 MiB pages
+const PAGE_DIRECTORY_LEVEL: usize = 2;
+
+#[derive(Debug)]
+pub(crate) struct AddressSpace {
+    pml4_frame: PhysicalFrame,
+}
+
+impl AddressSpace {
+    fn new() -> Result<Self, PagingError> {
+        Ok(Self {
+            pml4_frame: PageTable::allocate()?,
+        })
+    }
+
+    /// Walks down to the table at `level`, splitting the 2 MiB pages on the way
+    ///
+    /// The missing tables are created if `is_creating_missing`, else the walk stops at the first one
+    fn get_table(
+        &mut self,
+        virtual_address: u64,
+        level: usize,
+        is_creating_missing: bool,
+    ) -> Result<&mut PageTable, PagingError> {
+        let indices = get_table_indices(virtual_address);
+        // Safe : the PML4 and every table it refers to belong to this address space
+        let mut table =
+            unsafe { PageTable::from_physical_address(self.pml4_frame.start_address()) };
+        for (table_level, index) in indices.iter().enumerate().take(level) {
+            let entry = &mut table.0[*index];
+            if !entry.is_present() {
+                if !is_creating_missing {
+                    return Err(PagingError::NotMapped);
+                }
+                *entry =
+                    PageTableEntry::new(PageTable::allocate()?.start_address(), PageFlags::TABLE);
+            } else if entry.is_huge() {
+                if table_level != PAGE_DIRECTORY_LEVEL {
+                    return Err(PagingError::InsideGiantPage);
+                }
+                Self::split_huge_page(entry)?;
+            }
+            // Safe : see above
+            table = unsafe { PageTable::from_physical_address(entry.physical_address()) };
+        }
+        Ok(table)
+    }
+    fn split_huge_page(entry: &mut PageTableEntry) -> Result<(), PagingError> {
+        let table_frame = PageTable::allocate()?;
+        // Safe : the table was just allocated
+        let table = unsafe { PageTable::from_physical_address(table_frame.start_address()) };
+        let flags = entry.flags().without(PageFlags::HUGE);
+        for (index, small_entry) in table.0.iter_mut().enumerate() {
+            *small_entry =
+                PageTableEntry::new(entry.physical_address() + index as u64 * PAGE_SIZE, flags);
+        }
+        *entry = PageTableEntry::new(table_frame.start_address(), PageFlags::TABLE);
+        Ok(())
+    }
+    fn get_page_entry(
+        &mut self,
+        virtual_address: u64,
+        is_creating_missing: bool,
+    ) -> Result<&mut PageTableEntry, PagingError> {
+        let index = get_table_indices(virtual_address)[3];
+        let table = self.get_table(virtual_address, 3, is_creating_missing)?;
+        Ok(&mut table.0[index])
+    }
+
+    /// Maps the 4 KiB page containing `virtual_address`
+    pub(crate) fn map_page(
+        &mut self,
+        virtual_address: u64,
+        frame: PhysicalFrame,
+        flags: PageFlags,
+    ) -> Result<(), PagingError> {
+        let entry = self.get_page_entry(virtual_address, true)?;
+        if entry.is_present() {
+            return Err(PagingError::AlreadyMapped);
+        }
+        *entry = PageTableEntry::new(frame.start_address(), flags | PageFlags::PRESENT);
+        cpu::invalidate_page(virtual_address);
+        Ok(())
+    }
+    /// Unmaps the 4 KiB page containing `virtual_address`, and returns its frame, which isn't deallocated
+    pub(crate) fn unmap_page(
+        &mut self,
+        virtual_address: u64,
+    ) -> Result<PhysicalFrame, PagingError> {
+        let entry = self.get_page_entry(virtual_address, false)?;
+        if !entry.is_present() {
+            return Err(PagingError::NotMapped);
+        }
+        let frame = PhysicalFrame::containing_address(entry.physical_address());
+        *entry = PageTableEntry::UNUSED;
+        cpu::invalidate_page(virtual_address);
+        Ok(frame)
+    }
+    /// Replaces the flags of the 4 KiB page containing `virtual_address`
+    pub(crate) fn protect_page(
+        &mut self,
+        virtual_address: u64,
+        flags: PageFlags,
+    ) -> Result<(), PagingError> {
+        let entry = self.get_page_entry(virtual_address, false)?;
+        if !entry.is_present() {
+            return Err(PagingError::NotMapped);
+        }
+        *entry = PageTableEntry::new(entry.physical_address(), flags | PageFlags::PRESENT);
+        cpu::invalidate_page(virtual_address);
+        Ok(())
+    }
+
+    fn map_huge_page(
+        &mut self,
+        virtual_address: u64,
+        physical_address: u64,
+        flags: PageFlags,
+    ) -> Result<(), PagingError> {
+        let index = get_table_indices(virtual_address)[PAGE_DIRECTORY_LEVEL];
+        let table = self.get_table(virtual_address, PAGE_DIRECTORY_LEVEL, true)?;
+        if table.0[index].is_present() {
+            return Err(PagingError::AlreadyMapped);
+        }
+        table.0[index] = PageTableEntry::new(
+            physical_address,
+            flags | PageFlags::PRESENT | PageFlags::HUGE,
+        );
+        Ok(())
+    }
+    fn map_range(
+        &mut self,
+        virtual_address: u64,
+        range: PhysicalRange,
+        flags: PageFlags,
+    ) -> Result<(), PagingError> {
+        let first_address = range.start - range.start % PAGE_SIZE;
+        for physical_address in (first_address..range.end).step_by(PAGE_SIZE as usize) {
+            let page_address = virtual_address + (physical_address - first_address);
+            self.map_page(
+                page_address,
+                PhysicalFrame::containing_address(physical_address),
+                flags,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the flags of the 4 KiB pages mapping `range` from `virtual_address`
+    fn protect_range(
+        &mut self,
+        virtual_address: u64,
+        range: PhysicalRange,
+        flags: PageFlags,
+    ) -> Result<(), PagingError> {
+        let first_address = range.start - range.start % PAGE_SIZE;
+        for physical_address in (first_address..range.end).step_by(PAGE_SIZE as usize) {
+            self.protect_page(virtual_address + (physical_address - first_address), flags)?;
+        }
+        Ok(())
+    }
+
+    /// # Safety
+    /// This address space must map at least the running code, its stack and its data at their current addresses
+    unsafe fn activate(&self) {
+        cpu::write_cr3(self.pml4_frame.start_address());
+    }
+}
+
+/// Section flags of the PE format, which UEFI images use
+const PE_SECTION_EXECUTABLE: u32 = 0x2000_0000;
+const PE_SECTION_WRITABLE: u32 = 0x8000_0000;
+
+/// Returns the flags each page of the kernel image needs, according to the sections of its PE header
+///
+/// # Safety
+/// `kernel_image` must be the range where UEFI loaded this executable
+unsafe fn get_kernel_image_page_flags(kernel_image: PhysicalRange, page_offset: u64) -> PageFlags {
+    let image = kernel_image.start as *const u8;
+    let read_u16 = |offset: usize| image.add(offset).cast::<u16>().read_unaligned();
+    let read_u32 = |offset: usize| image.add(offset).cast::<u32>().read_unaligned();
+    let pe_header_offset = read_u32(0x3C) as usize;
+    let coff_header_offset = pe_header_offset + 4;
+    let section_count = read_u16(coff_header_offset + 2) as usize;
+    let optional_header_size = read_u16(coff_header_offset + 16) as usize;
+    let section_table_offset = coff_header_offset + 20 + optional_header_size;
+    let mut flags = PageFlags::KERNEL_READ_ONLY_DATA;
+    for section_index in 0..section_count {
+        let section_offset = section_table_offset + section_index * 40;
+        let section_size = read_u32(section_offset + 8) as u64;
+        let section_start = read_u32(section_offset + 12) as u64;
+        let characteristics = read_u32(section_offset + 36);
+        let page_range = PhysicalRange::from_start_and_size(page_offset, PAGE_SIZE);
+        if !page_range.overlaps(&PhysicalRange::from_start_and_size(
+            section_start,
+            section_size,
+        )) {
+            continue;
+        }
+        if characteristics & PE_SECTION_WRITABLE != 0 {
+            flags = flags | PageFlags::WRITABLE;
+        }
+        if characteristics & PE_SECTION_EXECUTABLE != 0 {
+            flags = flags.without(PageFlags::NO_EXECUTE);
+        }
+    }
+    flags
+}
+
+/// The kernel keeps running from its identity mapped image, which gets the permissions of its sections too
+fn protect_identity_mapped_kernel_image(
+    address_space: &mut AddressSpace,
+    kernel_image: PhysicalRange,
+) -> Result<(), PagingError> {
+    for page_offset in (0..kernel_image.size()).step_by(PAGE_SIZE as usize) {
+        // Safe : the kernel image range comes from UEFI
+        let flags = unsafe { get_kernel_image_page_flags(kernel_image, page_offset) };
+        address_space.protect_page(kernel_image.start + page_offset, flags)?;
+    }
+    Ok(())
+}
+
+fn map_kernel_image(
+    address_space: &mut AddressSpace,
+    kernel_image: PhysicalRange,
+) -> Result<(), PagingError> {
+    for page_offset in (0..kernel_image.size()).step_by(PAGE_SIZE as usize) {
+        // Safe : the kernel image range comes from UEFI
+        let flags = unsafe { get_kernel_image_page_flags(kernel_image, page_offset) };
+        address_space.map_page(
+            KERNEL_IMAGE_ADDRESS + page_offset,
+            PhysicalFrame::containing_address(kernel_image.start + page_offset),
+            flags | PageFlags::GLOBAL,
+        )?;
+    }
+    Ok(())
+}
+
+/// Builds the kernel address space and switches to it :
+/// - the physical memory is both identity mapped, as UEFI did, and mapped from `DIRECT_MAP_ADDRESS`, the identity
+///   mapping being no-execute but for the running kernel image and the runtime services code
+/// - the kernel image is also mapped from `KERNEL_IMAGE_ADDRESS`, which nothing runs from yet
+/// - the frame buffer is mapped from `FRAME_BUFFER_ADDRESS`
+/// - the null page is left unmapped
+///
+/// Returns the new address of the first frame buffer pixel
+///
+/// # Panics
+/// Panics if the physical memory is exhausted while building the page tables
+pub(crate) fn init(
+    memory_map: &MemoryMap<'static>,
+    kernel_image: PhysicalRange,
+    frame_buffer_range: PhysicalRange,
+) -> u64 {
+    let physical_memory_end = memory_map
+        .entries()
+        .map(|descriptor| {
+            PhysicalRange::from_start_and_page_count(descriptor.phys_start, descriptor.page_count)
+                .end
+        })
+        .fold(MIN_IDENTITY_MAPPED_SIZE, u64::max)
+        .max(frame_buffer_range.end)
+        .next_multiple_of(HUGE_PAGE_SIZE);
+    let mut address_space = AddressSpace::new().expect("cannot allocate the kernel PML4");
+    for physical_address in (0..physical_memory_end).step_by(HUGE_PAGE_SIZE as usize) {
+        address_space
+            .map_huge_page(physical_address, physical_address, PageFlags::KERNEL_DATA)
+            .and_then(|_| {
+                address_space.map_huge_page(
+                    DIRECT_MAP_ADDRESS + physical_address,
+                    physical_address,
+                    PageFlags::KERNEL_DATA,
+                )
+            })
+            .expect("cannot map the physical memory");
+    }
+    address_space
+        .unmap_page(0)
+        .expect("cannot unmap the null page");
+    map_kernel_image(&mut address_space, kernel_image).expect("cannot map the kernel image");
+    protect_identity_mapped_kernel_image(&mut address_space, kernel_image)
+        .expect("cannot protect the identity mapped kernel image");
+    // The runtime services run from their identity mapping, their code regions holding their data too
+    for descriptor in memory_map
+        .entries()
+        .filter(|descriptor| descriptor.ty == MemoryType::RUNTIME_SERVICES_CODE)
+    {
+        let range =
+            PhysicalRange::from_start_and_page_count(descriptor.phys_start, descriptor.page_count);
+        address_space
+            .protect_range(range.start, range, PageFlags::PRESENT | PageFlags::WRITABLE)
+            .expect("cannot make the runtime services code executable");
+    }
+    address_space
+        .map_range(
+            FRAME_BUFFER_ADDRESS,
+            frame_buffer_range,
+            PageFlags::KERNEL_DATA | PageFlags::WRITE_THROUGH,
+        )
+        .expect("cannot map the frame buffer");
+    // Safe :
+    // - No-execute bits are allowed before switching, as the new tables have some : without, they're reserved bits
+    // - The running code, its stack and its data are identity mapped, like UEFI did, the code being the only
+    //   executable part of the kernel image, which is never written to
+    unsafe {
+        cpu::enable_page_protections();
+        address_space.activate();
+        PHYSICAL_MEMORY_OFFSET.store(DIRECT_MAP_ADDRESS, Ordering::Relaxed);
+    }
+    *KERNEL_ADDRESS_SPACE.lock() = Some(address_space);
+    FRAME_BUFFER_ADDRESS + frame_buffer_range.start % PAGE_SIZE
+}
+
+/// Runs `f` with the kernel address space locked
+///
+/// Must not be called from an interrupt handler, which could deadlock
+///
+/// # Panics
+/// Panics if the kernel address space isn't built yet
+pub(crate) fn with_kernel_address_space<R>(f: impl FnOnce(&mut AddressSpace) -> R) -> R {
+    let mut address_space = KERNEL_ADDRESS_SPACE.lock();
+    f(address_space
+        .as_mut()
+        .expect("the kernel address space must be built first"))
+}
+
+/// Maps a new kernel stack of `page_count` pages above an unmapped guard page, so that an overflow page faults
+/// instead of silently corrupting the memory below, and returns the stack top
+pub(crate) fn allocate_kernel_stack(page_count: u64) -> Result<u64, PagingError> {
+    let guard_page_address =
+        NEXT_KERNEL_STACK_ADDRESS.fetch_add((page_count + 1) * PAGE_SIZE, Ordering::Relaxed);
+    let stack_bottom = guard_page_address + PAGE_SIZE;
+    with_kernel_address_space(|address_space| {
+        for page_index in 0..page_count {
+            let frame =
+                frame_allocator::allocate_frame().ok_or(PagingError::FrameAllocationFailed)?;
+            let page_address = stack_bottom + page_index * PAGE_SIZE;
+            if let Err(error) = address_space.map_page(page_address, frame, PageFlags::KERNEL_DATA)
+            {
+                frame_allocator::deallocate_frame(frame);
+                return Err(error);
+            }
+        }
+        Ok(stack_bottom + page_count * PAGE_SIZE)
+    })
+}
@@ -1,51 +1,240 @@
-use crate::kernel::console::Console;
-use crate::kernel::native_graphics::FrameBuffer;
+use crate::kernel::acpi::madt::Madt;
+use crate::kernel::console::cursor::CursorShape;
+use crate::kernel::console::font::{Font, FontScale};
+use crate::kernel::console::kprintln;
+use crate::kernel::console::psf::PsfFont;
+use crate::kernel::cpu::gdt;
+use crate::kernel::keyboard::{Layout, Ps2Error};
+use crate::kernel::memory::{frame_allocator, heap, memory_map, paging, PhysicalRange, PAGE_SIZE};
+use crate::kernel::serial::SerialError;
+use crate::kernel::shell::Shell;
+use alloc::boxed::Box;
+use log::LevelFilter;
 use uefi::table::boot::MemoryMap;
 use uefi::table::{Runtime, SystemTable};
 
+pub(crate) mod acpi;
 pub(crate) mod console;
+pub(crate) mod cpu;
+pub(crate) mod interrupts;
+pub(crate) mod keyboard;
+pub(crate) mod logger;
+pub(crate) mod memory;
 pub(crate) mod native_graphics;
+pub(crate) mod serial;
+pub(crate) mod shell;
+pub(crate) mod time;
 
 #[derive(Debug)]
 pub(crate) struct KernelContext {
-    pub(crate) frame_buffer: FrameBuffer,
+    pub(crate) frame_buffer_range: PhysicalRange,
     pub(crate) system_table: SystemTable<Runtime>,
     pub(crate) memory_map: MemoryMap<'static>,
+    pub(crate) kernel_image: PhysicalRange,
+    pub(crate) boot_options: BootOptions,
+    pub(crate) timestamp_counter_frequency: u64,
 }
 
-#[allow(unused_must_use)]
-#[allow(unconditional_panic)]
-pub(super) fn load(context: &mut KernelContext, console: &mut Console) -> ! {
-    context.frame_buffer.blacken();
+/// Chosen at boot through the load options
+#[derive(Debug)]
+pub(crate) struct BootOptions {
+    pub(crate) keyboard_layout: &'static Layout,
+    /// The console output is mirrored to COM1 at this baud rate, if any
+    pub(crate) serial_baud_rate: Option<u32>,
+    /// The PSF font read from the ESP, parsed once the heap is ready
+    pub(crate) font_file: Option<&'static [u8]>,
+    pub(crate) font_scale: FontScale,
+    pub(crate) cursor_shape: CursorShape,
+    /// The frame buffer benchmark runs at boot, logging its results
+    pub(crate) is_benchmark_enabled: bool,
+    /// The console draws into RAM, then presents the changes to the video memory
+    pub(crate) is_back_buffer_enabled: bool,
+}
 
-    for _ in 0..50 {
-        console.print("\n");
-    }
+impl BootOptions {
+    pub(crate) const DEFAULT: Self = Self {
+        keyboard_layout: keyboard::DEFAULT_LAYOUT,
+        serial_baud_rate: Some(serial::DEFAULT_BAUD_RATE),
+        font_file: None,
+        font_scale: FontScale::Auto,
+        cursor_shape: CursorShape::Underline,
+        is_benchmark_enabled: false,
+        is_back_buffer_enabled: true,
+    };
+}
 
-    console.print("Hello world !\nWelcome to Untitled OS :)\n\n");
+const KERNEL_STACK_PAGE_COUNT: u64 = 32;
 
-    console.print("\t1. One\n");
-    console.print("\t2. Two\n");
-    console.print("\t3. Three...");
-    console.print("\r\t3. Free !!!\n\n");
+pub(super) fn load(context: KernelContext) -> ! {
+    time::init(context.timestamp_counter_frequency);
+    logger::init();
+    // First, so that even the earliest panics and log lines are written to COM1
+    let serial_status = init_serial(context.boot_options.serial_baud_rate);
+    frame_allocator::init(
+        &context.memory_map,
+        &[context.kernel_image, context.frame_buffer_range],
+    );
+    heap::init();
+    // Off the UEFI stack, which gets reclaimed once the kernel runs on its own stack
+    let context: &'static KernelContext = Box::leak(Box::new(context));
+    let frame_buffer_address = paging::init(
+        &context.memory_map,
+        context.kernel_image,
+        context.frame_buffer_range,
+    );
+    // The console must draw through the new frame buffer mapping
+    let mut cells_status = Ok(());
+    if let Some(mut console) = console::lock() {
+        // Safe : the frame buffer was just mapped from there
+        unsafe {
+            console.frame_buffer_mut().remap(frame_buffer_address);
+        }
+        console.set_font_scale(context.boot_options.font_scale);
+        console.set_cursor_shape(context.boot_options.cursor_shape);
+        cells_status = console.allocate_cells();
+        console.enable_scrollback();
+    }
+    logger::add_sink(console::log_on_screen, LevelFilter::Info);
+    if let Err(error) = serial_status {
+        log::warn!("no serial output : {:?}", error);
+    }
+    if let Err(error) = cells_status {
+        log::warn!(
+            "no console cells, so no cursor nor scrollback : {:?}",
+            error
+        );
+    }
+    if let Some(font_file) = context.boot_options.font_file {
+        init_font(font_file);
+    }
+    if context.boot_options.is_benchmark_enabled {
+        run_frame_buffer_benchmark();
+    }
+    // After the benchmark, which measures the drawing into the video memory
+    if context.boot_options.is_back_buffer_enabled {
+        init_back_buffer();
+    }
+    log::info!(
+        "timestamp counter at {} MHz",
+        context.timestamp_counter_frequency / 1_000_000
+    );
+    memory_map::log_report(&context.memory_map);
+    gdt::init();
+    interrupts::init();
+    let rsdp_address =
+        acpi::find_rsdp_address(&context.system_table).expect("cannot find the ACPI RSDP");
+    // Safe : the RSDP was given by UEFI, and the ACPI tables are identity mapped
+    let madt = unsafe { Madt::find(rsdp_address) }.expect("cannot find the ACPI MADT");
+    log::info!(
+        "local APIC at {:#x}, {} IO APIC(s)",
+        madt.local_apic_address,
+        madt.io_apics.len()
+    );
+    interrupts::init_controllers(&madt);
+    match interrupts::start_timer(console::CURSOR_BLINK_PERIOD, console::blink_cursor) {
+        Ok(frequency) => log::info!("local APIC timer at {} MHz", frequency / 1_000_000),
+        Err(error) => log::warn!("no cursor blinking : {:?}", error),
+    }
+    let keyboard_status = keyboard::init();
+    match keyboard_status {
+        Ok(()) => log::info!(
+            "PS/2 keyboard ready, {} layout",
+            context.boot_options.keyboard_layout.name
+        ),
+        Err(error) => log::warn!("no PS/2 keyboard : {:?}", error),
+    }
+    // The UEFI stack has no guard page, so an overflow wouldn't fault
+    let stack_top = paging::allocate_kernel_stack(KERNEL_STACK_PAGE_COUNT)
+        .expect("cannot allocate the kernel stack");
+    // Safe : the stack was just allocated for the kernel only
+    unsafe { cpu::switch_stack(stack_top, move || run(context, keyboard_status)) }
+}
 
-    console.print("The four next chars aren't printable and may be ignored : µéùà\n\n");
+/// Mirrors the console and writes the log lines to COM1, unless disabled
+fn init_serial(baud_rate: Option<u32>) -> Result<(), SerialError> {
+    let Some(baud_rate) = baud_rate else {
+        return Ok(());
+    };
+    serial::COM1.init(baud_rate)?;
+    serial::start_mirroring(serial::COM1);
+    logger::add_sink(serial::log_to_com1, LevelFilter::Debug);
+    Ok(())
+}
 
-    console.print("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Aliquam id maximus leo, ut aliquam nulla. In hac habitasse platea dictumst. Pellentesque dictum egestas arcu vel ultricies. Sed pretium fermentum quam vitae molestie. Aenean vitae imperdiet ex. Quisque nec sagittis risus, quis accumsan ligula. Donec cursus convallis feugiat. Nullam eu velit odio. Donec blandit diam a erat venenatis, vitae tempor enim dapibus. Ut in pretium urna. Aenean convallis lacus at elit suscipit, vel posuere turpis hendrerit. Nulla lacus risus, tincidunt vitae ipsum vel, egestas tempus nisi. Nullam tincidunt eget risus at elementum. In iaculis, est sit amet congue volutpat, mauris arcu fringilla diam, a porttitor velit quam in magna.\n\n");
+/// Keeps the built-in font if the PSF font is invalid
+fn init_font(font_file: &'static [u8]) {
+    let psf_font = match PsfFont::parse(font_file) {
+        Ok(psf_font) => psf_font,
+        Err(error) => {
+            log::warn!("cannot use the PSF font : {:?}", error);
+            return;
+        }
+    };
+    let glyph_resolution = psf_font.glyph_resolution();
+    let glyph_count = psf_font.glyph_count();
+    if let Some(mut console) = console::lock() {
+        console.set_font(Font::Psf(psf_font));
+    }
+    log::info!(
+        "PSF font with {} glyphs of {}x{}",
+        glyph_count,
+        glyph_resolution.horizontal,
+        glyph_resolution.vertical
+    );
+}
 
-    console.print("Will it scroll ? (01/10)\n");
-    console.print("Will it scroll ? (02/10)\n");
-    console.print("Will it scroll ? (03/10)\n");
-    console.print("Will it scroll ? (04/10)\n");
-    console.print("Will it scroll ? (05/10)\n");
-    console.print("Will it scroll ? (06/10)\n");
-    console.print("Will it scroll ? (07/10)\n");
-    console.print("Will it scroll ? (08/10)\n");
-    console.print("Will it scroll ? (09/10)\n");
-    console.print("Will it scroll ? (10/10)\n");
-    console.print("Let's see...");
+/// The screen gets cleared afterwards
+fn run_frame_buffer_benchmark() {
+    let Some(mut console) = console::lock() else {
+        return;
+    };
+    let benchmark = native_graphics::benchmark::run(console.frame_buffer_mut());
+    console.redraw();
+    // The console must be released before logging to it
+    drop(console);
+    log::info!("frame buffer benchmark : {}", benchmark);
+}
 
-    // TODO check multiline panic printing
-    //0 / 0;
-    loop {}
+fn init_back_buffer() {
+    let Some(mut console) = console::lock() else {
+        return;
+    };
+    let back_buffer_status = console.frame_buffer_mut().enable_back_buffer();
+    // The console must be released before logging to it
+    drop(console);
+    if let Err(error) = back_buffer_status {
+        log::warn!("no frame buffer back buffer : {:?}", error);
+    }
+}
+
+fn run(context: &KernelContext, keyboard_status: Result<(), Ps2Error>) -> ! {
+    // Safe :
+    // - The kernel runs on its own stack, where `switch_stack` moved the context reference and the keyboard status,
+    //   and the context was moved to the heap
+    // - The frame buffer description and the MADT were copied out, the memory map was allocated with a type of
+    //   its own, and the other firmware data used, like the ACPI tables and the runtime services, live in memory
+    //   that is never reclaimed
+    let reclaimed_frame_count =
+        unsafe { frame_allocator::reclaim_boot_services_memory(&context.memory_map) };
+    log::info!(
+        "{} KiB of boot services memory reclaimed",
+        reclaimed_frame_count as u64 * PAGE_SIZE / 1024
+    );
+    if let Some(mut console) = console::lock() {
+        // The log lines stay on the first terminal, shown with Alt+F1
+        console.show_terminal(console::OUTPUT_TERMINAL);
+    }
+    kprintln!("Hello world !\nWelcome to \x1b[1;36mUntitled OS\x1b[0m :)\n");
+    if keyboard_status.is_err() {
+        kprintln!("No PS/2 keyboard found");
+        cpu::halt_forever();
+    }
+    if let Err(error) = shell::init() {
+        log::warn!("no shell built-in commands : {:?}", error);
+    }
+    kprintln!(
+        "Type help to list the commands, with the {} keyboard layout",
+        context.boot_options.keyboard_layout.name
+    );
+    Shell::new(context.boot_options.keyboard_layout).run(context)
 }
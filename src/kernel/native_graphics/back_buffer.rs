@@ -0,0 +1,71 @@
+use crate::kernel::native_graphics::{HardwarePixel, PixelRectangle};
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+
+/// More changes than that get merged into the last dirty rectangle
+const MAX_DIRTY_RECTANGLE_COUNT: usize = 8;
+
+/// A copy of the frame buffer in RAM, which is drawn into and read from instead of the much slower video memory
+///
+/// Its lines are as long as the hardware ones, so that the same offsets work in both
+pub(super) struct BackBuffer {
+    pub(super) pixels: Vec<HardwarePixel>,
+    /// The changes not yet presented to the video memory
+    dirty_rectangles: [PixelRectangle; MAX_DIRTY_RECTANGLE_COUNT],
+    dirty_rectangle_count: usize,
+}
+
+impl BackBuffer {
+    /// Starts from the current content of the video memory
+    pub(super) fn new(video_memory: &[HardwarePixel]) -> Result<Self, TryReserveError> {
+        let mut pixels = Vec::new();
+        pixels.try_reserve_exact(video_memory.len())?;
+        pixels.extend_from_slice(video_memory);
+        Ok(Self {
+            pixels,
+            dirty_rectangles: [PixelRectangle::EMPTY; MAX_DIRTY_RECTANGLE_COUNT],
+            dirty_rectangle_count: 0,
+        })
+    }
+
+    /// Merged with a dirty rectangle it touches, if any
+    pub(super) fn mark_dirty(&mut self, rectangle: PixelRectangle) {
+        if rectangle.is_empty() {
+            return;
+        }
+        let dirty_rectangles = &mut self.dirty_rectangles[..self.dirty_rectangle_count];
+        if let Some(dirty_rectangle) = dirty_rectangles
+            .iter_mut()
+            .find(|dirty_rectangle| dirty_rectangle.touches(&rectangle))
+        {
+            *dirty_rectangle = dirty_rectangle.union(&rectangle);
+        } else if self.dirty_rectangle_count < MAX_DIRTY_RECTANGLE_COUNT {
+            self.dirty_rectangles[self.dirty_rectangle_count] = rectangle;
+            self.dirty_rectangle_count += 1;
+        } else {
+            let last_rectangle = &mut self.dirty_rectangles[MAX_DIRTY_RECTANGLE_COUNT - 1];
+            *last_rectangle = last_rectangle.union(&rectangle);
+        }
+    }
+    /// The rectangles are clean again once taken
+    pub(super) fn take_dirty_rectangles(&mut self) -> impl Iterator<Item = PixelRectangle> {
+        let dirty_rectangle_count = self.dirty_rectangle_count;
+        self.dirty_rectangle_count = 0;
+        self.dirty_rectangles
+            .into_iter()
+            .take(dirty_rectangle_count)
+    }
+}
+
+impl core::fmt::Debug for BackBuffer {
+    /// The pixels are left out
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BackBuffer")
+            .field("pixel_count", &self.pixels.len())
+            .field(
+                "dirty_rectangles",
+                &&self.dirty_rectangles[..self.dirty_rectangle_count],
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,101 @@
+use crate::kernel::native_graphics::{FrameBuffer, Pixel, PixelPosition, PixelRectangle};
+use crate::kernel::time;
+use core::fmt::{Display, Formatter};
+use core::time::Duration;
+
+const ITERATION_COUNT: u32 = 10;
+/// Like a console scrolling by one row of 8x16 chars
+const SCROLLED_LINE_COUNT: usize = 16;
+const FILL_PIXEL: Pixel = Pixel::rgb(32, 32, 32);
+
+/// The average durations of the pixel by pixel drawing, as the console used to do, and of the bulk operations
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FrameBufferBenchmark {
+    per_pixel_fill: Duration,
+    bulk_fill: Duration,
+    per_pixel_scroll: Duration,
+    rectangle_scroll: Duration,
+    line_scroll: Duration,
+}
+
+impl Display for FrameBufferBenchmark {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "fill : {:?} per pixel, {:?} in bulk ; scroll : {:?} per pixel, {:?} by rectangle, {:?} by lines",
+            self.per_pixel_fill,
+            self.bulk_fill,
+            self.per_pixel_scroll,
+            self.rectangle_scroll,
+            self.line_scroll
+        )
+    }
+}
+
+/// Draws over the whole screen, which must be redrawn afterwards
+///
+/// Measures the video memory only while the back buffer is not enabled
+pub(crate) fn run(frame_buffer: &mut FrameBuffer) -> FrameBufferBenchmark {
+    FrameBufferBenchmark {
+        per_pixel_fill: measure(frame_buffer, fill_per_pixel),
+        bulk_fill: measure(frame_buffer, |frame_buffer| frame_buffer.fill(FILL_PIXEL)),
+        per_pixel_scroll: measure(frame_buffer, scroll_per_pixel),
+        rectangle_scroll: measure(frame_buffer, scroll_by_rectangle),
+        line_scroll: measure(frame_buffer, |frame_buffer| {
+            let line_count = frame_buffer.resolution.vertical;
+            frame_buffer.copy_lines(0, SCROLLED_LINE_COUNT..line_count);
+        }),
+    }
+}
+
+fn measure(frame_buffer: &mut FrameBuffer, draw: fn(&mut FrameBuffer)) -> Duration {
+    let start = time::get_uptime();
+    for _ in 0..ITERATION_COUNT {
+        draw(frame_buffer);
+    }
+    (time::get_uptime() - start) / ITERATION_COUNT
+}
+
+fn fill_per_pixel(frame_buffer: &mut FrameBuffer) {
+    for horizontal in 0..frame_buffer.resolution.horizontal {
+        for vertical in 0..frame_buffer.resolution.vertical {
+            let position = PixelPosition {
+                horizontal,
+                vertical,
+            };
+            frame_buffer.draw_pixel_if_visible(position, FILL_PIXEL);
+        }
+    }
+}
+
+fn scroll_per_pixel(frame_buffer: &mut FrameBuffer) {
+    for vertical in SCROLLED_LINE_COUNT..frame_buffer.resolution.vertical {
+        for horizontal in 0..frame_buffer.resolution.horizontal {
+            let src = PixelPosition {
+                horizontal,
+                vertical,
+            };
+            let dest = PixelPosition {
+                horizontal,
+                vertical: vertical - SCROLLED_LINE_COUNT,
+            };
+            frame_buffer.copy_one_pixel(dest, src);
+        }
+    }
+}
+
+fn scroll_by_rectangle(frame_buffer: &mut FrameBuffer) {
+    let mut src = PixelRectangle {
+        position: PixelPosition {
+            horizontal: 0,
+            vertical: SCROLLED_LINE_COUNT,
+        },
+        resolution: frame_buffer.resolution,
+    };
+    src.resolution.vertical -= SCROLLED_LINE_COUNT;
+    let dest = PixelPosition {
+        horizontal: 0,
+        vertical: 0,
+    };
+    frame_buffer.copy_rectangle(dest, src);
+}
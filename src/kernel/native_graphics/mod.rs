@@ -1,7 +1,17 @@
+use crate::kernel::memory::PhysicalRange;
+use crate::kernel::native_graphics::back_buffer::BackBuffer;
+use alloc::collections::TryReserveError;
+use core::fmt::{Display, Formatter};
 use core::mem;
+use core::ops::Range;
+use core::ptr;
+use core::slice;
 use uefi::proto::console::gop;
 use uefi::proto::console::gop::{ModeInfo, PixelFormat};
 
+mod back_buffer;
+pub(crate) mod benchmark;
+
 #[derive(Clone, Copy, Debug)]
 enum HardwarePixelFormat {
     Bgr,
@@ -40,7 +50,7 @@ impl Into<Pixel> for HardwarePixel {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) struct Pixel {
     red: u8,
     green: u8,
@@ -91,24 +101,102 @@ impl Resolution {
             && self.vertical <= Self::MAX_SUPPORTED.vertical
     }
     pub(crate) const fn accepts_position(&self, position: PixelPosition) -> bool {
-        position.horizontal < self.horizontal && position.horizontal < self.horizontal
+        position.horizontal < self.horizontal && position.vertical < self.vertical
     }
 }
 
+/// A rectangle of pixels, given by its upper left corner and its size
 #[derive(Clone, Copy, Debug)]
+pub(crate) struct PixelRectangle {
+    pub(crate) position: PixelPosition,
+    pub(crate) resolution: Resolution,
+}
+
+impl PixelRectangle {
+    const EMPTY: Self = Self {
+        position: PixelPosition {
+            horizontal: 0,
+            vertical: 0,
+        },
+        resolution: Resolution {
+            horizontal: 0,
+            vertical: 0,
+        },
+    };
+
+    const fn is_empty(&self) -> bool {
+        self.resolution.horizontal == 0 || self.resolution.vertical == 0
+    }
+    const fn horizontal_end(&self) -> usize {
+        self.position.horizontal + self.resolution.horizontal
+    }
+    const fn vertical_end(&self) -> usize {
+        self.position.vertical + self.resolution.vertical
+    }
+    /// Overlapping or side by side
+    const fn touches(&self, other: &Self) -> bool {
+        self.position.horizontal <= other.horizontal_end()
+            && other.position.horizontal <= self.horizontal_end()
+            && self.position.vertical <= other.vertical_end()
+            && other.position.vertical <= self.vertical_end()
+    }
+    /// The smallest rectangle holding both
+    fn union(&self, other: &Self) -> Self {
+        let position = PixelPosition {
+            horizontal: self.position.horizontal.min(other.position.horizontal),
+            vertical: self.position.vertical.min(other.position.vertical),
+        };
+        Self {
+            position,
+            resolution: Resolution {
+                horizontal: self.horizontal_end().max(other.horizontal_end()) - position.horizontal,
+                vertical: self.vertical_end().max(other.vertical_end()) - position.vertical,
+            },
+        }
+    }
+    /// The part of the rectangle within `resolution`, which may be empty
+    fn clip(&self, resolution: Resolution) -> Self {
+        let PixelPosition {
+            horizontal,
+            vertical,
+        } = self.position;
+        let horizontal_end = (horizontal + self.resolution.horizontal).min(resolution.horizontal);
+        let vertical_end = (vertical + self.resolution.vertical).min(resolution.vertical);
+        Self {
+            position: self.position,
+            resolution: Resolution {
+                horizontal: horizontal_end.saturating_sub(horizontal),
+                vertical: vertical_end.saturating_sub(vertical),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
 pub(crate) struct FrameBuffer {
     mut_ptr_to_pixels: *mut HardwarePixel,
     pixel_format: HardwarePixelFormat,
     /// may be larger than horizontal_resolution, for performance reasons, or due to hardware restrictions !
     hardware_width_in_pixels: usize,
     pub(crate) resolution: Resolution,
+    /// Where the pixels live in physical memory, which must never be handed out by the frame allocator
+    physical_range: PhysicalRange,
+    /// Drawn into instead of the video memory once enabled, until presented
+    back_buffer: Option<BackBuffer>,
 }
 
+// Safe : the pixels are only reached through the one frame buffer owning them
+unsafe impl Send for FrameBuffer {}
+
 impl FrameBuffer {
     pub(crate) fn from_uefi_graphics_output_protocol(
         mut frame_buffer: gop::FrameBuffer,
         mode_info: ModeInfo,
     ) -> Self {
+        let physical_range = PhysicalRange::from_start_and_size(
+            frame_buffer.as_mut_ptr() as u64,
+            frame_buffer.size() as u64,
+        );
         Self {
             // Safe : UEFI frame buffers must be 32*n-byte-sized for our supported pixel formats
             mut_ptr_to_pixels: unsafe { mem::transmute(frame_buffer.as_mut_ptr()) },
@@ -118,25 +206,58 @@ impl FrameBuffer {
                 horizontal: mode_info.resolution().0,
                 vertical: mode_info.resolution().1,
             },
+            physical_range,
+            back_buffer: None,
         }
     }
-    pub(crate) fn get_pixel_if_visible(&self, position: PixelPosition) -> Option<Pixel> {
-        if !self.resolution.accepts_position(position) {
-            return None;
+    pub(crate) const fn physical_range(&self) -> PhysicalRange {
+        self.physical_range
+    }
+    /// # Safety
+    /// The pixels must be mapped from `virtual_address` in the active address space
+    pub(crate) unsafe fn remap(&mut self, virtual_address: u64) {
+        self.mut_ptr_to_pixels = virtual_address as *mut HardwarePixel;
+    }
+    /// Needs the heap, and keeps drawing into the video memory if there is not enough of it
+    ///
+    /// Nothing drawn reaches the screen anymore until `present` is called
+    pub(crate) fn enable_back_buffer(&mut self) -> Result<(), TryReserveError> {
+        if self.back_buffer.is_some() {
+            return Ok(());
         }
-        // Safe :
-        // - This code is available after we got the hardware frame buffer without panicking, whose geometries are known
-        // - Once the boot stage is over, we may keep reading from the frame buffer : our OS won't support other cases
-        // - We just have validated the position
-        unsafe {
-            let hardware_pixel = self
-                .mut_ptr_to_pixels
-                .offset(
-                    (position.vertical * self.hardware_width_in_pixels + position.horizontal)
-                        as isize,
-                )
-                .read_volatile();
-            Some(hardware_pixel.into())
+        // Safe : the video memory holds `hardware_width_in_pixels` pixels for each line, and is only read once here
+        let video_memory = unsafe {
+            slice::from_raw_parts(
+                self.mut_ptr_to_pixels,
+                self.hardware_width_in_pixels * self.resolution.vertical,
+            )
+        };
+        self.back_buffer = Some(BackBuffer::new(video_memory)?);
+        Ok(())
+    }
+    /// Copies the changed regions of the back buffer to the video memory, line by line, without reading the latter
+    pub(crate) fn present(&mut self) {
+        let Some(back_buffer) = &mut self.back_buffer else {
+            return;
+        };
+        for rectangle in back_buffer.take_dirty_rectangles() {
+            let PixelRectangle {
+                position,
+                resolution,
+            } = rectangle.clip(self.resolution);
+            for vertical in position.vertical..position.vertical + resolution.vertical {
+                let offset = vertical * self.hardware_width_in_pixels + position.horizontal;
+                // Safe :
+                // - The rectangle was clipped to the screen, and both buffers have the same geometry
+                // - The back buffer never overlaps the video memory
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        back_buffer.pixels.as_ptr().add(offset),
+                        self.mut_ptr_to_pixels.add(offset),
+                        resolution.horizontal,
+                    );
+                }
+            }
         }
     }
 
@@ -154,13 +275,20 @@ impl FrameBuffer {
     }
     unsafe fn draw_pixel_unchecked(&mut self, position: PixelPosition, pixel: Pixel) {
         let hardware_pixel = HardwarePixel::new(pixel, self.pixel_format);
-        self.mut_ptr_to_pixels
+        self.get_drawn_pixels_mut()
             .offset(
                 (position.vertical * self.hardware_width_in_pixels + position.horizontal) as isize,
             )
             .write_volatile(hardware_pixel);
+        self.mark_dirty(PixelRectangle {
+            position,
+            resolution: Resolution {
+                horizontal: 1,
+                vertical: 1,
+            },
+        });
     }
-    pub(crate) fn copy_one_pixel(&self, dest: PixelPosition, src: PixelPosition) {
+    pub(crate) fn copy_one_pixel(&mut self, dest: PixelPosition, src: PixelPosition) {
         let src_offset = (src.vertical * self.hardware_width_in_pixels + src.horizontal) as isize;
         let dest_offset =
             (dest.vertical * self.hardware_width_in_pixels + dest.horizontal) as isize;
@@ -169,26 +297,155 @@ impl FrameBuffer {
         // - Once the boot stage is over, we may keep writing into the frame buffer : our OS won't support other cases
         // - We just have validated the positions
         unsafe {
-            self.mut_ptr_to_pixels
+            let pixels = self.get_drawn_pixels_mut();
+            pixels
                 .offset(src_offset)
-                .copy_to(self.mut_ptr_to_pixels.offset(dest_offset), 1);
+                .copy_to(pixels.offset(dest_offset), 1);
         }
-    }
-    pub(crate) fn blacken(&mut self) {
-        self.fill(Pixel::BLACK);
+        self.mark_dirty(PixelRectangle {
+            position: dest,
+            resolution: Resolution {
+                horizontal: 1,
+                vertical: 1,
+            },
+        });
     }
     pub(crate) fn fill(&mut self, pixel: Pixel) {
-        for horizontal in 0..self.resolution.horizontal {
-            for vertical in 0..self.resolution.vertical {
-                let position = PixelPosition {
-                    horizontal,
-                    vertical,
-                };
-                // Safe : position comes from self.resolution
-                unsafe {
-                    self.draw_pixel_unchecked(position, pixel);
-                }
+        let screen = PixelRectangle {
+            position: PixelPosition {
+                horizontal: 0,
+                vertical: 0,
+            },
+            resolution: self.resolution,
+        };
+        self.fill_rectangle(screen, pixel);
+    }
+    /// Clipped to the screen, each line being filled at once
+    pub(crate) fn fill_rectangle(&mut self, rectangle: PixelRectangle, pixel: Pixel) {
+        let rectangle = rectangle.clip(self.resolution);
+        self.mark_dirty(rectangle);
+        let PixelRectangle {
+            position,
+            resolution,
+        } = rectangle;
+        let hardware_pixel = HardwarePixel::new(pixel, self.pixel_format);
+        for vertical in position.vertical..position.vertical + resolution.vertical {
+            let line_start = PixelPosition {
+                horizontal: position.horizontal,
+                vertical,
+            };
+            // Safe : the rectangle was clipped to the screen
+            unsafe { self.get_pixels_mut(line_start, resolution.horizontal) }.fill(hardware_pixel);
+        }
+    }
+    /// Clipped to the screen, both at `src` and at `dest`, the rectangles being allowed to overlap
+    pub(crate) fn copy_rectangle(&mut self, dest: PixelPosition, src: PixelRectangle) {
+        let src = src.clip(self.resolution);
+        let dest_rectangle = PixelRectangle {
+            position: dest,
+            resolution: src.resolution,
+        }
+        .clip(self.resolution);
+        self.mark_dirty(dest_rectangle);
+        let resolution = dest_rectangle.resolution;
+        let pixels = self.get_drawn_pixels_mut();
+        let copy_line = |vertical_offset: usize| {
+            let src_offset = self.get_offset(PixelPosition {
+                horizontal: src.position.horizontal,
+                vertical: src.position.vertical + vertical_offset,
+            });
+            let dest_offset = self.get_offset(PixelPosition {
+                horizontal: dest.horizontal,
+                vertical: dest.vertical + vertical_offset,
+            });
+            // Safe : both lines were clipped to the screen, and `ptr::copy` allows them to overlap
+            unsafe {
+                ptr::copy(
+                    pixels.add(src_offset),
+                    pixels.add(dest_offset),
+                    resolution.horizontal,
+                );
             }
+        };
+        // The lines overwritten first must already have been copied, when moving down
+        if dest.vertical > src.position.vertical {
+            (0..resolution.vertical).rev().for_each(copy_line);
+        } else {
+            (0..resolution.vertical).for_each(copy_line);
+        }
+    }
+    /// Moves whole lines, padding included, with a single memmove, `src_lines` and the destination being clipped to the screen
+    pub(crate) fn copy_lines(&mut self, dest_line: usize, src_lines: Range<usize>) {
+        let src_end = src_lines.end.min(self.resolution.vertical);
+        let line_count = src_end
+            .saturating_sub(src_lines.start)
+            .min(self.resolution.vertical.saturating_sub(dest_line));
+        if line_count == 0 {
+            return;
         }
+        self.mark_dirty(PixelRectangle {
+            position: PixelPosition {
+                horizontal: 0,
+                vertical: dest_line,
+            },
+            resolution: Resolution {
+                horizontal: self.resolution.horizontal,
+                vertical: line_count,
+            },
+        });
+        let pixels = self.get_drawn_pixels_mut();
+        // Safe :
+        // - The frame buffer holds `hardware_width_in_pixels` pixels for each of its lines
+        // - The lines were clipped to the screen, and `ptr::copy` allows them to overlap
+        unsafe {
+            ptr::copy(
+                pixels.add(src_lines.start * self.hardware_width_in_pixels),
+                pixels.add(dest_line * self.hardware_width_in_pixels),
+                line_count * self.hardware_width_in_pixels,
+            );
+        }
+    }
+
+    /// The back buffer if enabled, else the video memory
+    fn get_drawn_pixels_mut(&mut self) -> *mut HardwarePixel {
+        match &mut self.back_buffer {
+            Some(back_buffer) => back_buffer.pixels.as_mut_ptr(),
+            None => self.mut_ptr_to_pixels,
+        }
+    }
+    /// To be presented, if drawn into the back buffer
+    fn mark_dirty(&mut self, rectangle: PixelRectangle) {
+        if let Some(back_buffer) = &mut self.back_buffer {
+            back_buffer.mark_dirty(rectangle);
+        }
+    }
+
+    const fn get_offset(&self, position: PixelPosition) -> usize {
+        position.vertical * self.hardware_width_in_pixels + position.horizontal
+    }
+    /// # Safety
+    /// The `count` pixels from `position` must be on the same line of the screen
+    unsafe fn get_pixels_mut(
+        &mut self,
+        position: PixelPosition,
+        count: usize,
+    ) -> &mut [HardwarePixel] {
+        let offset = self.get_offset(position);
+        slice::from_raw_parts_mut(self.get_drawn_pixels_mut().add(offset), count)
+    }
+}
+
+impl Display for FrameBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}x{} pixels, {} pixels per line, {:?} format, {} KiB at {:#x}",
+            self.resolution.horizontal,
+            self.resolution.vertical,
+            self.hardware_width_in_pixels,
+            self.pixel_format,
+            self.physical_range.size() / 1024,
+            self.physical_range.start
+        )
     }
 }
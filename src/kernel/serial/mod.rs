@@ -0,0 +1,146 @@
+use crate::kernel::cpu::port;
+use core::sync::atomic::{AtomicU16, Ordering};
+
+/// The UART clock divided by the divisor latch gives the baud rate
+const MAX_BAUD_RATE: u32 = 115_200;
+pub(crate) const DEFAULT_BAUD_RATE: u32 = MAX_BAUD_RATE;
+
+const DATA_REGISTER: u16 = 0;
+const INTERRUPT_ENABLE_REGISTER: u16 = 1;
+/// Divisor latch registers, replacing the two first registers while `DIVISOR_LATCH_ACCESS` is set
+const DIVISOR_LOW_REGISTER: u16 = 0;
+const DIVISOR_HIGH_REGISTER: u16 = 1;
+const FIFO_CONTROL_REGISTER: u16 = 2;
+const LINE_CONTROL_REGISTER: u16 = 3;
+const MODEM_CONTROL_REGISTER: u16 = 4;
+const LINE_STATUS_REGISTER: u16 = 5;
+
+const DIVISOR_LATCH_ACCESS: u8 = 1 << 7;
+/// 8 data bits, no parity, 1 stop bit
+const EIGHT_N_ONE: u8 = 0x03;
+/// Enabled and cleared FIFOs, with an interrupt threshold of 14 bytes
+const ENABLE_AND_CLEAR_FIFOS: u8 = 0xC7;
+const FIFO_SIZE: usize = 16;
+/// Data terminal ready, request to send, and the two auxiliary outputs, the second one enabling IRQs
+const NORMAL_MODE: u8 = 0x0F;
+const LOOPBACK_MODE: u8 = 0x1E;
+const LOOPBACK_TEST_BYTE: u8 = 0xAE;
+const DATA_READY: u8 = 1 << 0;
+const TRANSMITTER_HOLDING_REGISTER_EMPTY: u8 = 1 << 5;
+
+/// Status polls before dropping the bytes to send, so that a missing UART can't hang the kernel
+const TIMEOUT_POLL_COUNT: usize = 100_000;
+
+/// Serial port whose output is mirrored from the console, 0 when not mirroring
+static MIRROR_BASE_PORT: AtomicU16 = AtomicU16::new(0);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SerialError {
+    /// The baud rate must divide `MAX_BAUD_RATE`
+    UnsupportedBaudRate,
+    /// Also happens when there is no UART at all
+    LoopbackTestFailed,
+}
+
+/// A 16550 UART, only writing
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SerialPort {
+    base_port: u16,
+}
+
+pub(crate) const COM1: SerialPort = SerialPort { base_port: 0x3F8 };
+
+impl SerialPort {
+    /// # Safety
+    /// `register` must be an existing register
+    unsafe fn read_register(&self, register: u16) -> u8 {
+        port::read_u8(self.base_port + register)
+    }
+    /// # Safety
+    /// `register` must be an existing register, and `value` must keep the UART usable by the kernel
+    unsafe fn write_register(&self, register: u16, value: u8) {
+        port::write_u8(self.base_port + register, value);
+    }
+
+    /// Sets the UART to `baud_rate` 8N1, with FIFOs and without interrupts, then checks it works with a loopback test
+    pub(crate) fn init(&self, baud_rate: u32) -> Result<(), SerialError> {
+        if !MAX_BAUD_RATE.is_multiple_of(baud_rate) {
+            return Err(SerialError::UnsupportedBaudRate);
+        }
+        let divisor = (MAX_BAUD_RATE / baud_rate) as u16;
+        // Safe : the UART is only programmed here, before anything is sent
+        unsafe {
+            self.write_register(INTERRUPT_ENABLE_REGISTER, 0);
+            self.write_register(LINE_CONTROL_REGISTER, DIVISOR_LATCH_ACCESS);
+            self.write_register(DIVISOR_LOW_REGISTER, divisor as u8);
+            self.write_register(DIVISOR_HIGH_REGISTER, (divisor >> 8) as u8);
+            self.write_register(LINE_CONTROL_REGISTER, EIGHT_N_ONE);
+            self.write_register(FIFO_CONTROL_REGISTER, ENABLE_AND_CLEAR_FIFOS);
+
+            self.write_register(MODEM_CONTROL_REGISTER, LOOPBACK_MODE);
+            self.write_register(DATA_REGISTER, LOOPBACK_TEST_BYTE);
+            let is_looped_back = self.wait_for_line_status(DATA_READY)
+                && self.read_register(DATA_REGISTER) == LOOPBACK_TEST_BYTE;
+            self.write_register(MODEM_CONTROL_REGISTER, NORMAL_MODE);
+            if !is_looped_back {
+                return Err(SerialError::LoopbackTestFailed);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns false on timeout
+    fn wait_for_line_status(&self, status: u8) -> bool {
+        for _ in 0..TIMEOUT_POLL_COUNT {
+            // Safe : reading the line status has no side effect
+            if unsafe { self.read_register(LINE_STATUS_REGISTER) } & status != 0 {
+                return true;
+            }
+            port::wait_a_bit();
+        }
+        false
+    }
+
+    /// Sends `s` with `\n` turned into `\r\n`, filling the transmit FIFO each time it gets empty
+    pub(crate) fn write_str(&self, s: &str) {
+        let mut bytes = s
+            .bytes()
+            .flat_map(|byte| {
+                let carriage_return = if byte == b'\n' { Some(b'\r') } else { None };
+                carriage_return.into_iter().chain(Some(byte))
+            })
+            .peekable();
+        while bytes.peek().is_some() {
+            if !self.wait_for_line_status(TRANSMITTER_HOLDING_REGISTER_EMPTY) {
+                return;
+            }
+            for byte in bytes.by_ref().take(FIFO_SIZE) {
+                // Safe : the transmit FIFO is empty, so it has room for `FIFO_SIZE` bytes
+                unsafe {
+                    self.write_register(DATA_REGISTER, byte);
+                }
+            }
+        }
+    }
+}
+
+/// Sends everything the console prints to `serial_port` too
+pub(crate) fn start_mirroring(serial_port: SerialPort) {
+    MIRROR_BASE_PORT.store(serial_port.base_port, Ordering::Relaxed);
+}
+
+/// Sends `s` to the mirror serial port, if any
+///
+/// Doesn't take any lock, so that the panic handler can always use it
+pub(crate) fn mirror(s: &str) {
+    let base_port = MIRROR_BASE_PORT.load(Ordering::Relaxed);
+    if base_port != 0 {
+        SerialPort { base_port }.write_str(s);
+    }
+}
+
+/// Log sink writing to COM1, which must be initialized
+pub(crate) fn log_to_com1(line: &str) {
+    COM1.write_str(line);
+    COM1.write_str("\n");
+}
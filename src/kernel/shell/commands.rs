@@ -0,0 +1,161 @@
+use crate::kernel::console::{kprint, kprintln};
+use crate::kernel::memory::memory_map::{MemoryKind, MemoryTotals};
+use crate::kernel::memory::{frame_allocator, heap, memory_map, PAGE_SIZE};
+use crate::kernel::shell::{Command, RegisterError};
+use crate::kernel::{console, logger, shell, time, KernelContext};
+use alloc::format;
+use uefi::table::runtime::ResetType;
+use uefi::Status;
+
+const BUILT_IN_COMMANDS: [Command; 10] = [
+    Command {
+        name: "help",
+        description: "lists the commands",
+        run: print_help,
+    },
+    Command {
+        name: "clear",
+        description: "clears the screen",
+        run: clear,
+    },
+    Command {
+        name: "echo",
+        description: "prints its arguments",
+        run: echo,
+    },
+    Command {
+        name: "dmesg",
+        description: "prints the kept log lines",
+        run: print_dmesg,
+    },
+    Command {
+        name: "meminfo",
+        description: "prints the usable memory, the free frames and the heap usage",
+        run: print_memory_info,
+    },
+    Command {
+        name: "memmap",
+        description: "prints the UEFI memory map, merged into contiguous ranges, and the totals",
+        run: print_memory_map,
+    },
+    Command {
+        name: "fbinfo",
+        description:
+            "prints the resolution, the line length and the pixel format of the frame buffer",
+        run: print_frame_buffer_info,
+    },
+    Command {
+        name: "time",
+        description: "prints the uptime and the date of the UEFI clock",
+        run: print_time,
+    },
+    Command {
+        name: "reboot",
+        description: "restarts the computer",
+        run: reboot,
+    },
+    Command {
+        name: "shutdown",
+        description: "powers the computer off",
+        run: shut_down,
+    },
+];
+
+const MIB: u64 = 1024 * 1024;
+
+pub(super) fn register_built_in_commands() -> Result<(), RegisterError> {
+    for command in BUILT_IN_COMMANDS {
+        shell::register_command(command)?;
+    }
+    Ok(())
+}
+
+fn print_help(_: &KernelContext, _: &[&str]) {
+    for command in shell::get_commands().iter().flatten() {
+        kprintln!("{:<10} {}", command.name, command.description);
+    }
+}
+
+fn clear(_: &KernelContext, _: &[&str]) {
+    kprint!("\x1b[H\x1b[2J");
+}
+
+fn echo(_: &KernelContext, arguments: &[&str]) {
+    kprintln!("{}", arguments.join(" "));
+}
+
+/// The log lines are also on the first terminal, shown with Alt+F1
+fn print_dmesg(_: &KernelContext, _: &[&str]) {
+    logger::for_each_dmesg_line(|line| kprintln!("{}", line));
+}
+
+/// Usable memory is what the kernel may use once the boot services are exited, reclaimed or not
+fn print_memory_info(context: &KernelContext, _: &[&str]) {
+    let usable_page_count: u64 = context
+        .memory_map
+        .entries()
+        .filter(|descriptor| MemoryKind::of(descriptor.ty) == MemoryKind::Usable)
+        .map(|descriptor| descriptor.page_count)
+        .sum();
+    let free_frame_count =
+        frame_allocator::with_frame_allocator(|frame_allocator| frame_allocator.free_frame_count());
+    let heap_stats = heap::get_stats();
+    kprintln!(
+        "usable memory : {} MiB",
+        usable_page_count * PAGE_SIZE / MIB
+    );
+    kprintln!(
+        "free frames : {} MiB",
+        free_frame_count as u64 * PAGE_SIZE / MIB
+    );
+    kprintln!(
+        "heap : {} KiB used, {} KiB free, {} KiB used at most",
+        heap_stats.used / 1024,
+        heap_stats.free() / 1024,
+        heap_stats.peak / 1024
+    );
+}
+
+fn print_memory_map(context: &KernelContext, _: &[&str]) {
+    let ranges = memory_map::get_merged_ranges(&context.memory_map);
+    for range in &ranges {
+        kprintln!("{}", range);
+    }
+    kprintln!("{}", MemoryTotals::of(&ranges));
+}
+
+fn print_frame_buffer_info(_: &KernelContext, _: &[&str]) {
+    // The console must be released before printing
+    let description = console::lock().map(|console| format!("{}", console.frame_buffer()));
+    if let Some(description) = description {
+        kprintln!("{}", description);
+    }
+}
+
+fn print_time(context: &KernelContext, _: &[&str]) {
+    let uptime = time::get_uptime();
+    kprintln!(
+        "uptime : {}.{:03} s",
+        uptime.as_secs(),
+        uptime.subsec_millis()
+    );
+    // Safe : the runtime services are still identity mapped, and only called from the shell
+    match unsafe { context.system_table.runtime_services() }.get_time() {
+        Ok(date) => kprintln!("date : {}", date),
+        Err(error) => kprintln!("no date : {:?}", error.status()),
+    }
+}
+
+fn reboot(context: &KernelContext, _: &[&str]) {
+    // Safe : the runtime services are still identity mapped, and only called from the shell
+    unsafe { context.system_table.runtime_services() }.reset(ResetType::COLD, Status::SUCCESS, None)
+}
+
+fn shut_down(context: &KernelContext, _: &[&str]) {
+    // Safe : the runtime services are still identity mapped, and only called from the shell
+    unsafe { context.system_table.runtime_services() }.reset(
+        ResetType::SHUTDOWN,
+        Status::SUCCESS,
+        None,
+    )
+}
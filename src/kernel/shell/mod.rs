@@ -0,0 +1,169 @@
+use crate::kernel::console::kprintln;
+use crate::kernel::console::line_editor::{LineEditor, LineEvent};
+use crate::kernel::keyboard::{KeyEvent, Layout};
+use crate::kernel::{console, cpu, keyboard, KernelContext};
+use alloc::vec::Vec;
+use spin::Mutex;
+
+mod commands;
+
+const PROMPT: &str = "\x1b[1;36m>\x1b[0m ";
+const MAX_COMMAND_COUNT: usize = 32;
+
+/// Something the shell can run, like `echo`
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Command {
+    pub(crate) name: &'static str,
+    /// A single line, listed by `help`
+    pub(crate) description: &'static str,
+    /// Given the words typed after the command name
+    pub(crate) run: fn(&KernelContext, &[&str]),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum RegisterError {
+    NameAlreadyUsed,
+    TooManyCommands,
+}
+
+static COMMANDS: Mutex<[Option<Command>; MAX_COMMAND_COUNT]> =
+    Mutex::new([None; MAX_COMMAND_COUNT]);
+
+/// Makes `command` available to the shell, next to the built-in commands
+pub(crate) fn register_command(command: Command) -> Result<(), RegisterError> {
+    let mut commands = COMMANDS.lock();
+    if commands
+        .iter()
+        .flatten()
+        .any(|registered_command| registered_command.name == command.name)
+    {
+        return Err(RegisterError::NameAlreadyUsed);
+    }
+    let free_slot = commands
+        .iter_mut()
+        .find(|slot| slot.is_none())
+        .ok_or(RegisterError::TooManyCommands)?;
+    *free_slot = Some(command);
+    Ok(())
+}
+
+/// A copy, so that the commands run without the lock, and may register commands too
+fn get_commands() -> [Option<Command>; MAX_COMMAND_COUNT] {
+    *COMMANDS.lock()
+}
+
+/// Registers the built-in commands
+///
+/// Must be called once
+pub(crate) fn init() -> Result<(), RegisterError> {
+    commands::register_built_in_commands()
+}
+
+/// Reads command lines typed on the output terminal with a line editor, and runs them, the command names being
+/// completed with Tab
+#[derive(Debug)]
+pub(crate) struct Shell {
+    line_editor: LineEditor,
+}
+
+impl Shell {
+    pub(crate) fn new(layout: &'static Layout) -> Self {
+        Self {
+            line_editor: LineEditor::new(layout, PROMPT),
+        }
+    }
+
+    /// The console shortcuts, like Alt+F1, are handled first
+    pub(crate) fn run(&mut self, context: &KernelContext) -> ! {
+        self.line_editor.start_line();
+        loop {
+            let key_event = wait_for_key_event();
+            let is_console_shortcut =
+                console::lock().is_some_and(|mut console| console.handle_key_event(key_event));
+            if is_console_shortcut {
+                continue;
+            }
+            match self.line_editor.handle_key_event(key_event) {
+                Some(LineEvent::Submitted(line)) => {
+                    run_line(context, &line);
+                    self.line_editor.start_line();
+                }
+                Some(LineEvent::CompletionRequested) => self.complete_command_name(),
+                None => {}
+            }
+        }
+    }
+
+    /// Completes the name as much as the matching commands allow, then lists them if there are several
+    fn complete_command_name(&mut self) {
+        let typed_name = self.line_editor.text_before_cursor();
+        // Only the first word is completed
+        if typed_name.contains(' ') {
+            return;
+        }
+        let commands = get_commands();
+        let names: Vec<&str> = commands
+            .iter()
+            .flatten()
+            .map(|command| command.name)
+            .filter(|name| name.starts_with(typed_name.as_str()))
+            .collect();
+        let Some(common_prefix) = names
+            .iter()
+            .copied()
+            .reduce(|common_prefix, name| get_common_prefix(common_prefix, name))
+        else {
+            return;
+        };
+        let completion = &common_prefix[typed_name.len()..];
+        if names.len() == 1 {
+            self.line_editor.insert(completion);
+            self.line_editor.insert(" ");
+        } else if !completion.is_empty() {
+            self.line_editor.insert(completion);
+        } else {
+            self.line_editor.print_above(&names.join("  "));
+        }
+    }
+}
+
+/// Words are separated by whitespace, the first one being the command name
+fn run_line(context: &KernelContext, line: &str) {
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return;
+    };
+    let arguments: Vec<&str> = words.collect();
+    let commands = get_commands();
+    match commands
+        .iter()
+        .flatten()
+        .find(|command| command.name == name)
+    {
+        Some(command) => (command.run)(context, &arguments),
+        None => kprintln!("{}: unknown command, see help", name),
+    }
+}
+
+fn get_common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let length = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, a_char), b_char)| a_char != b_char)
+        .map_or(a.len().min(b.len()), |((index, _), _)| index);
+    &a[..length]
+}
+
+/// Halts until a key is pressed or released
+fn wait_for_key_event() -> KeyEvent {
+    loop {
+        cpu::disable_interrupts();
+        match keyboard::read_key_event() {
+            Some(key_event) => {
+                cpu::enable_interrupts();
+                return key_event;
+            }
+            None => cpu::enable_interrupts_and_halt(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::kernel::cpu;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+
+const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Timestamp counter ticks per second, 0 until initialized
+static TIMESTAMP_COUNTER_FREQUENCY: AtomicU64 = AtomicU64::new(0);
+static BOOT_TIMESTAMP: AtomicU64 = AtomicU64::new(0);
+
+/// Starts the uptime clock, from the timestamp counter frequency measured while booting
+///
+/// Must be called once, as early as possible
+pub(crate) fn init(timestamp_counter_frequency: u64) {
+    BOOT_TIMESTAMP.store(cpu::read_timestamp_counter(), Ordering::Relaxed);
+    TIMESTAMP_COUNTER_FREQUENCY.store(timestamp_counter_frequency, Ordering::Relaxed);
+}
+
+/// Monotonic time since `init`, zero before
+pub(crate) fn get_uptime() -> Duration {
+    let frequency = TIMESTAMP_COUNTER_FREQUENCY.load(Ordering::Relaxed);
+    if frequency == 0 {
+        return Duration::ZERO;
+    }
+    let ticks =
+        cpu::read_timestamp_counter().saturating_sub(BOOT_TIMESTAMP.load(Ordering::Relaxed));
+    Duration::from_nanos((ticks as u128 * NANOSECONDS_PER_SECOND / frequency as u128) as u64)
+}
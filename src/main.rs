@@ -1,9 +1,12 @@
 #![no_main]
 #![no_std]
 
+extern crate alloc;
+
 mod kernel;
 mod uefi_boot;
 
+use crate::kernel::console;
 use crate::kernel::console::{Console, DisposablePanicWriter};
 use crate::kernel::load;
 use crate::uefi_boot::boot;
@@ -11,26 +14,22 @@ use core::panic::PanicInfo;
 use uefi::table::{Boot, SystemTable};
 use uefi::{entry, Handle, Status};
 
-static mut PANIC_CONSOLE: Option<*mut Console> = None;
-
 #[entry]
 fn main(_handle: Handle, system_table: SystemTable<Boot>) -> Status {
-    let kernel_context = boot(system_table);
-    if kernel_context.is_none() {
+    let boot_result = boot(system_table);
+    if boot_result.is_none() {
         return Status::UNSUPPORTED;
     }
-    let mut kernel_context = kernel_context.unwrap();
-    let mut console = Console::new(kernel_context.frame_buffer);
-    unsafe {
-        PANIC_CONSOLE = Some(&mut console);
-    }
-    load(&mut kernel_context, &mut console);
+    let (kernel_context, frame_buffer) = boot_result.unwrap();
+    console::install(Console::new(frame_buffer));
+    load(kernel_context);
 }
 
 #[panic_handler]
 unsafe fn panic(info: &PanicInfo) -> ! {
-    if let Some(console) = PANIC_CONSOLE {
-        DisposablePanicWriter::new(*console).panic(info);
+    // Safe : the code that panicked never resumes, so it never uses the console again
+    if let Some(mut console) = console::force_lock() {
+        DisposablePanicWriter::new(&mut console).panic(info);
     }
     loop {}
 }
@@ -1,19 +1,32 @@
+use crate::kernel::native_graphics::FrameBuffer;
 use crate::kernel::KernelContext;
 use crate::uefi_boot::uefi_graphics::get_frame_buffer;
+use crate::uefi_boot::uefi_loaded_image::{get_boot_options, get_kernel_image_range};
+use crate::uefi_boot::uefi_time::measure_timestamp_counter_frequency;
 use uefi::table::boot::MemoryType;
 use uefi::table::{Boot, SystemTable};
 
+mod uefi_file;
 mod uefi_graphics;
+mod uefi_loaded_image;
+mod uefi_time;
 
-pub(super) fn boot(system_table: SystemTable<Boot>) -> Option<KernelContext> {
+/// The frame buffer comes apart, as it's owned by the console
+pub(super) fn boot(system_table: SystemTable<Boot>) -> Option<(KernelContext, FrameBuffer)> {
     let boot_services = system_table.boot_services();
     let frame_buffer = get_frame_buffer(boot_services)?;
+    let kernel_image = get_kernel_image_range(boot_services)?;
+    let boot_options = get_boot_options(boot_services);
+    let timestamp_counter_frequency = measure_timestamp_counter_frequency(boot_services);
     let (system_table, memory_map) =
         system_table.exit_boot_services(MemoryType::custom(0xFFFFFFFF));
     let kernel_context = KernelContext {
-        frame_buffer,
+        frame_buffer_range: frame_buffer.physical_range(),
         system_table,
         memory_map,
+        kernel_image,
+        boot_options,
+        timestamp_counter_frequency,
     };
-    Some(kernel_context)
+    Some((kernel_context, frame_buffer))
 }
@@ -0,0 +1,43 @@
+use core::slice;
+use uefi::prelude::BootServices;
+use uefi::proto::media::file::{File, FileAttribute, FileMode, RegularFile};
+use uefi::table::boot::MemoryType;
+use uefi::CStr16;
+
+/// Reads a whole file from the partition the kernel was loaded from, like `\fonts\ter-v32n.psf`
+///
+/// The content lives in loader data, which the frame allocator never hands out, so it outlives the boot services
+pub(super) fn read_esp_file(boot_services: &BootServices, path: &str) -> Option<&'static [u8]> {
+    const MAX_PATH_LENGTH: usize = 256;
+    let mut path_buffer = [0u16; MAX_PATH_LENGTH + 1];
+    let path = CStr16::from_str_with_buf(path, &mut path_buffer).ok()?;
+    let mut file_system = boot_services
+        .get_image_file_system(boot_services.image_handle())
+        .ok()?;
+    let mut file = file_system
+        .open_volume()
+        .ok()?
+        .open(path, FileMode::Read, FileAttribute::empty())
+        .ok()?
+        .into_regular_file()?;
+    file.set_position(RegularFile::END_OF_FILE).ok()?;
+    let size = file.get_position().ok()? as usize;
+    file.set_position(0).ok()?;
+    let pool = boot_services
+        .allocate_pool(MemoryType::LOADER_DATA, size)
+        .ok()?;
+    // Safe : the pool was just allocated with this size, and UEFI identity maps it
+    let content = unsafe { slice::from_raw_parts_mut(pool, size) };
+    let mut read_size = 0;
+    while read_size < size {
+        match file.read(&mut content[read_size..]) {
+            Ok(0) | Err(_) => {
+                // Safe : the pool was allocated above, and nothing refers to it anymore
+                let _ = unsafe { boot_services.free_pool(pool) };
+                return None;
+            }
+            Ok(chunk_size) => read_size += chunk_size,
+        }
+    }
+    Some(content)
+}
@@ -0,0 +1,84 @@
+use crate::kernel::console::cursor::CursorShape;
+use crate::kernel::console::font::FontScale;
+use crate::kernel::keyboard::Layout;
+use crate::kernel::memory::PhysicalRange;
+use crate::kernel::BootOptions;
+use crate::uefi_boot::uefi_file::read_esp_file;
+use uefi::prelude::BootServices;
+use uefi::proto::loaded_image::LoadedImage;
+
+pub(super) fn get_kernel_image_range(boot_services: &BootServices) -> Option<PhysicalRange> {
+    let loaded_image_protocol = boot_services
+        .open_protocol_exclusive::<LoadedImage>(boot_services.image_handle())
+        .ok()?;
+    let (image_base, image_size) = loaded_image_protocol.info();
+    Some(PhysicalRange::from_start_and_size(
+        image_base as u64,
+        image_size,
+    ))
+}
+
+/// Reads options like `keyboard=fr` or `serial=off` from the load options, as given by `bootx64.efi keyboard=fr`,
+/// ignoring the unknown ones
+///
+/// The font file of `font=<path>` is read from the ESP right away, as the boot services are needed
+pub(super) fn get_boot_options(boot_services: &BootServices) -> BootOptions {
+    const MAX_LOAD_OPTIONS_SIZE: usize = 256;
+    let mut boot_options = BootOptions::DEFAULT;
+    let Ok(loaded_image_protocol) =
+        boot_services.open_protocol_exclusive::<LoadedImage>(boot_services.image_handle())
+    else {
+        return boot_options;
+    };
+    let Ok(load_options) = loaded_image_protocol.load_options_as_cstr16() else {
+        return boot_options;
+    };
+    // There is no heap yet to convert the UCS-2 options into a `String`
+    let mut buffer = [0u8; MAX_LOAD_OPTIONS_SIZE];
+    let mut size = 0;
+    for c in load_options.iter() {
+        let c = char::from(*c);
+        if size + c.len_utf8() > MAX_LOAD_OPTIONS_SIZE {
+            break;
+        }
+        size += c.encode_utf8(&mut buffer[size..]).len();
+    }
+    // Reading files opens the loaded image protocol again
+    drop(loaded_image_protocol);
+    let load_options = core::str::from_utf8(&buffer[..size]).unwrap_or_default();
+    for option in load_options.split_whitespace() {
+        let Some((name, value)) = option.split_once('=') else {
+            continue;
+        };
+        match name {
+            "keyboard" => {
+                if let Some(layout) = Layout::find(value) {
+                    boot_options.keyboard_layout = layout;
+                }
+            }
+            "serial" if value == "off" => boot_options.serial_baud_rate = None,
+            "serial" => {
+                if let Ok(baud_rate) = value.parse() {
+                    boot_options.serial_baud_rate = Some(baud_rate);
+                }
+            }
+            "font" => boot_options.font_file = read_esp_file(boot_services, value),
+            "backbuffer" => boot_options.is_back_buffer_enabled = value != "off",
+            "benchmark" => boot_options.is_benchmark_enabled = value == "on",
+            "scale" if value == "auto" => boot_options.font_scale = FontScale::Auto,
+            "scale" => {
+                if let Ok(factor @ 1..) = value.parse() {
+                    boot_options.font_scale = FontScale::Fixed(factor);
+                }
+            }
+            "cursor" => match value {
+                "block" => boot_options.cursor_shape = CursorShape::Block,
+                "underline" => boot_options.cursor_shape = CursorShape::Underline,
+                "bar" => boot_options.cursor_shape = CursorShape::Bar,
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    boot_options
+}
@@ -0,0 +1,11 @@
+use crate::kernel::cpu;
+use uefi::prelude::BootServices;
+
+/// Counts the timestamp counter ticks during a UEFI stall, as there is no other clock to compare it to yet
+pub(super) fn measure_timestamp_counter_frequency(boot_services: &BootServices) -> u64 {
+    const STALL_MICROSECONDS: u64 = 10_000;
+    let start = cpu::read_timestamp_counter();
+    boot_services.stall(STALL_MICROSECONDS as usize);
+    let end = cpu::read_timestamp_counter();
+    (end - start) * 1_000_000 / STALL_MICROSECONDS
+}